@@ -0,0 +1,125 @@
+use anyhow::{bail, Context, Result};
+use std::{collections::BTreeMap, fmt::Display, path::Path, str::FromStr, time::Duration};
+
+/// The step of a solution a timing was taken for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Stage {
+    Parse,
+    Part(u8),
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part(p) => write!(f, "part{}", p),
+        }
+    }
+}
+
+impl FromStr for Stage {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "parse" {
+            return Ok(Stage::Parse);
+        }
+
+        match s.strip_prefix("part").and_then(|p| p.parse().ok()) {
+            Some(p) => Ok(Stage::Part(p)),
+            None => bail!("Unknown stage {}", s),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Summarizes a set of timings, or returns `None` if there are none.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        Some(Self {
+            min: *sorted.first()?,
+            median: sorted[sorted.len() / 2],
+            max: *sorted.last()?,
+        })
+    }
+}
+
+/// Median timings from an earlier run, saved as `<day> <stage> <nanoseconds>`
+/// lines so they can be compared against later.
+#[derive(Debug, Clone, Default)]
+pub struct Baseline {
+    medians: BTreeMap<(u8, Stage), Duration>,
+}
+
+impl Baseline {
+    pub fn read(path: &Path) -> Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Could not read baseline {}", path.display()))?;
+
+        let mut baseline = Baseline::default();
+
+        for (i, line) in contents.lines().enumerate() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let parse_line = || -> Result<(u8, Stage, Duration)> {
+                let mut fields = line.split_whitespace();
+                let mut next = || fields.next().context("Missing field");
+
+                let day = next()?.parse()?;
+                let stage = next()?.parse()?;
+                let nanos = next()?.parse()?;
+
+                Ok((day, stage, Duration::from_nanos(nanos)))
+            };
+
+            let (day, stage, median) = parse_line()
+                .with_context(|| format!("Invalid line {} in {}", i + 1, path.display()))?;
+
+            baseline.insert(day, stage, median);
+        }
+
+        Ok(baseline)
+    }
+
+    pub fn write(&self, path: &Path) -> Result<()> {
+        let mut contents = String::from("# <day> <stage> <median in nanoseconds>\n");
+
+        for ((day, stage), median) in &self.medians {
+            contents += &format!("{} {} {}\n", day, stage, median.as_nanos());
+        }
+
+        std::fs::write(path, contents)
+            .with_context(|| format!("Could not write baseline {}", path.display()))
+    }
+
+    pub fn insert(&mut self, day: u8, stage: Stage, median: Duration) {
+        self.medians.insert((day, stage), median);
+    }
+
+    pub fn get(&self, day: u8, stage: Stage) -> Option<Duration> {
+        self.medians.get(&(day, stage)).copied()
+    }
+}
+
+/// How much slower `current` is than `baseline`, as a percentage. Negative
+/// values mean it got faster.
+pub fn change_percent(baseline: Duration, current: Duration) -> f64 {
+    let baseline = baseline.as_secs_f64();
+
+    if baseline == 0.0 {
+        return 0.0;
+    }
+
+    (current.as_secs_f64() - baseline) / baseline * 100.0
+}
@@ -0,0 +1,47 @@
+use anyhow::Result;
+
+use super::Solution;
+use crate::parse::{parse_value, ParseError};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    /// The calorie total carried by each elf, in input order.
+    type Parsed = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(calories_input: &str) -> Result<Self::Parsed, ParseError> {
+        let mut group_sums: Vec<usize> = vec![0];
+
+        for (i, line) in calories_input.trim_end().lines().enumerate() {
+            if line.is_empty() {
+                group_sums.push(0);
+                continue;
+            }
+
+            let calories: usize =
+                parse_value(line, line, "a calorie count").map_err(|e| e.offset(i))?;
+
+            *group_sums.last_mut().unwrap() += calories;
+        }
+
+        Ok(group_sums)
+    }
+
+    fn part1(group_sums: &Self::Parsed) -> Result<Self::Answer1> {
+        let max_group_sum = group_sums.iter().max().copied().unwrap_or(0);
+
+        Ok(max_group_sum)
+    }
+
+    fn part2(group_sums: &Self::Parsed) -> Result<Self::Answer2> {
+        let mut group_sums = group_sums.clone();
+
+        group_sums.sort_by(|a, b| b.cmp(a));
+
+        let top_three_sum: usize = group_sums.iter().take(3).sum();
+
+        Ok(top_three_sum)
+    }
+}
@@ -0,0 +1,180 @@
+use anyhow::Result;
+use std::str::FromStr;
+
+use super::Solution;
+use crate::parse::{parse_lines, split_once, ParseError};
+
+#[derive(Debug, PartialEq)]
+pub enum RoundResult {
+    Win,
+    Lose,
+    Draw,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Choice {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+/// The second column of the strategy guide. Part 1 reads it as the choice to
+/// play, part 2 as the result the round needs to end with.
+#[derive(Debug, PartialEq)]
+pub enum Instruction {
+    X,
+    Y,
+    Z,
+}
+
+#[derive(Debug)]
+pub struct Round {
+    pub opponent: Choice,
+    pub instruction: Instruction,
+}
+
+impl Choice {
+    pub fn wins_against(&self) -> Choice {
+        match *self {
+            Choice::Rock => Choice::Scissors,
+            Choice::Paper => Choice::Rock,
+            Choice::Scissors => Choice::Paper,
+        }
+    }
+
+    pub fn get_desired_choice(&self, desired_result: &RoundResult) -> Choice {
+        match *self {
+            Choice::Rock => match *desired_result {
+                RoundResult::Win => Choice::Paper,
+                RoundResult::Lose => Choice::Scissors,
+                RoundResult::Draw => Choice::Rock,
+            },
+            Choice::Paper => match *desired_result {
+                RoundResult::Win => Choice::Scissors,
+                RoundResult::Lose => Choice::Rock,
+                RoundResult::Draw => Choice::Paper,
+            },
+            Choice::Scissors => match *desired_result {
+                RoundResult::Win => Choice::Rock,
+                RoundResult::Lose => Choice::Paper,
+                RoundResult::Draw => Choice::Scissors,
+            },
+        }
+    }
+
+    pub fn get_choice_score(&self) -> isize {
+        match *self {
+            Self::Rock => 1,
+            Self::Paper => 2,
+            Self::Scissors => 3,
+        }
+    }
+
+    pub fn get_round_score(&self, opponent_choice: &Choice) -> isize {
+        let choice_score = self.get_choice_score();
+        if *self == *opponent_choice {
+            return choice_score + RoundResult::Draw.get_result_score();
+        }
+
+        if self.wins_against() == *opponent_choice {
+            return choice_score + RoundResult::Win.get_result_score();
+        }
+
+        choice_score + RoundResult::Lose.get_result_score()
+    }
+}
+
+impl RoundResult {
+    pub fn get_result_score(&self) -> isize {
+        match *self {
+            RoundResult::Win => 6,
+            RoundResult::Lose => 0,
+            RoundResult::Draw => 3,
+        }
+    }
+}
+
+impl Instruction {
+    pub fn as_choice(&self) -> Choice {
+        match *self {
+            Instruction::X => Choice::Rock,
+            Instruction::Y => Choice::Paper,
+            Instruction::Z => Choice::Scissors,
+        }
+    }
+
+    pub fn as_result(&self) -> RoundResult {
+        match *self {
+            Instruction::X => RoundResult::Lose,
+            Instruction::Y => RoundResult::Draw,
+            Instruction::Z => RoundResult::Win,
+        }
+    }
+}
+
+impl FromStr for Round {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (opponent, instruction) = split_once(s, s, " ")?;
+
+        let opponent = match opponent {
+            "A" => Choice::Rock,
+            "B" => Choice::Paper,
+            "C" => Choice::Scissors,
+            _ => return Err(ParseError::new(s, opponent, "one of A, B or C")),
+        };
+
+        let instruction = match instruction {
+            "X" => Instruction::X,
+            "Y" => Instruction::Y,
+            "Z" => Instruction::Z,
+            _ => return Err(ParseError::new(s, instruction, "one of X, Y or Z")),
+        };
+
+        Ok(Round {
+            opponent,
+            instruction,
+        })
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Parsed = Vec<Round>;
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(strategy_lines: &str) -> Result<Self::Parsed, ParseError> {
+        parse_lines(strategy_lines)
+    }
+
+    fn part1(rounds: &Self::Parsed) -> Result<Self::Answer1> {
+        let total_score: isize = rounds
+            .iter()
+            .map(|round| {
+                let player_choice = round.instruction.as_choice();
+
+                player_choice.get_round_score(&round.opponent)
+            })
+            .sum();
+
+        Ok(total_score)
+    }
+
+    fn part2(rounds: &Self::Parsed) -> Result<Self::Answer2> {
+        let score: isize = rounds
+            .iter()
+            .map(|round| {
+                let desired_result = round.instruction.as_result();
+
+                let player_choice = round.opponent.get_desired_choice(&desired_result);
+
+                player_choice.get_choice_score() + desired_result.get_result_score()
+            })
+            .sum();
+
+        Ok(score)
+    }
+}
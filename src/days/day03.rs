@@ -0,0 +1,88 @@
+use anyhow::{bail, Context, Result};
+use std::collections::HashSet;
+
+use super::Solution;
+use crate::parse::ParseError;
+
+pub static ALPHABET: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+pub fn get_priority(ch: char) -> usize {
+    ALPHABET.find(ch).unwrap() + 1
+}
+
+/// Returns an item type that appears in every one of `group`, if there is one.
+pub fn get_common_char(group: &[&str]) -> Option<char> {
+    let (first, rest) = group.split_first()?;
+
+    let mut seen: HashSet<char> = HashSet::from_iter(first.chars());
+
+    for other in rest {
+        let seen_other: HashSet<char> = HashSet::from_iter(other.chars());
+        seen.retain(|ch| seen_other.contains(ch));
+    }
+
+    seen.into_iter().next()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Parsed = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, sack)| {
+                if let Some((pos, ch)) = sack.char_indices().find(|(_, c)| !c.is_ascii_alphabetic())
+                {
+                    let item = &sack[pos..pos + ch.len_utf8()];
+                    return Err(ParseError::new(sack, item, "an item type a-z or A-Z").offset(i));
+                }
+
+                if sack.len() % 2 != 0 {
+                    return Err(ParseError::new(sack, sack, "an even number of items").offset(i));
+                }
+
+                Ok(sack.to_string())
+            })
+            .collect()
+    }
+
+    fn part1(rucksack_input: &Self::Parsed) -> Result<Self::Answer1> {
+        rucksack_input
+            .iter()
+            .enumerate()
+            .map(|(i, sack)| {
+                let comp_one = &sack[..sack.len() / 2];
+                let comp_two = &sack[sack.len() / 2..];
+
+                let common_char = get_common_char(&[comp_one, comp_two]).with_context(|| {
+                    format!("Rucksack {} has no item in both compartments", i + 1)
+                })?;
+
+                Ok(get_priority(common_char))
+            })
+            .sum()
+    }
+
+    fn part2(rucksack_input: &Self::Parsed) -> Result<Self::Answer2> {
+        rucksack_input
+            .chunks(3)
+            .enumerate()
+            .map(|(i, g)| {
+                if g.len() < 3 {
+                    bail!("Group {} has only {} rucksacks", i + 1, g.len());
+                }
+
+                let group: Vec<&str> = g.iter().map(String::as_str).collect();
+                let common_char = get_common_char(&group)
+                    .with_context(|| format!("Group {} has no badge in common", i + 1))?;
+
+                Ok(get_priority(common_char))
+            })
+            .sum()
+    }
+}
@@ -0,0 +1,86 @@
+use anyhow::Result;
+use std::str::FromStr;
+
+use super::Solution;
+use crate::{
+    interval::IntervalSet,
+    parse::{parse_lines, parse_value, split_once, ParseError},
+};
+
+/// A pair of elves and the sections each one is assigned.
+#[derive(Debug)]
+pub struct Assignment {
+    pub sections_a: IntervalSet,
+    pub sections_b: IntervalSet,
+}
+
+impl FromStr for Assignment {
+    type Err = ParseError;
+
+    fn from_str(assignment: &str) -> Result<Self, Self::Err> {
+        let parse_range = |section: &str| -> Result<IntervalSet, ParseError> {
+            let (start, end) = split_once(assignment, section, "-")?;
+
+            let start = parse_value(assignment, start, "a section id")?;
+            let end_id = parse_value(assignment, end, "a section id")?;
+
+            if end_id < start {
+                return Err(ParseError::new(
+                    assignment,
+                    end,
+                    format!("a section id of at least {}", start),
+                ));
+            }
+
+            Ok(IntervalSet::from(start..=end_id))
+        };
+
+        let (section_a, section_b) = split_once(assignment, assignment, ",")?;
+
+        Ok(Self {
+            sections_a: parse_range(section_a)?,
+            sections_b: parse_range(section_b)?,
+        })
+    }
+}
+
+impl Assignment {
+    /// Whether one elf's sections are all covered by the other's.
+    pub fn has_contained_interval(&self) -> bool {
+        self.sections_a.is_subset(&self.sections_b) || self.sections_b.is_subset(&self.sections_a)
+    }
+
+    pub fn has_overlapping_interval(&self) -> bool {
+        !self.sections_a.intersection(&self.sections_b).is_empty()
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Parsed = Vec<Assignment>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(assignments: &Self::Parsed) -> Result<Self::Answer1> {
+        let count = assignments
+            .iter()
+            .filter(|a| a.has_contained_interval())
+            .count();
+
+        Ok(count)
+    }
+
+    fn part2(assignments: &Self::Parsed) -> Result<Self::Answer2> {
+        let count = assignments
+            .iter()
+            .filter(|a| a.has_overlapping_interval())
+            .count();
+
+        Ok(count)
+    }
+}
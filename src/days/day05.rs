@@ -0,0 +1,183 @@
+use anyhow::{bail, Context, Result};
+use std::str::FromStr;
+
+use super::Solution;
+use crate::parse::{parse_lines, parse_value, split_once, strip_prefix, ParseError};
+
+#[derive(Debug, Clone)]
+pub struct Crane {
+    pub stack: Vec<Vec<char>>,
+}
+
+#[derive(Debug)]
+pub struct Move {
+    pub from_idx: usize,
+    pub to_idx: usize,
+    pub count: usize,
+}
+
+impl Crane {
+    /// Fails if the move takes more crates than its stack has.
+    fn check_move(&self, m: &Move) -> Result<()> {
+        let available = self.stack[m.from_idx].len();
+
+        if available < m.count {
+            bail!(
+                "Stack {} only has {} crates, not {}",
+                m.from_idx + 1,
+                available,
+                m.count
+            );
+        }
+
+        Ok(())
+    }
+
+    pub fn move_crate(&mut self, m: &Move) -> Result<()> {
+        self.check_move(m)?;
+
+        for _ in 0..m.count {
+            let popped = self.stack[m.from_idx].pop().unwrap();
+            self.stack[m.to_idx].push(popped);
+        }
+
+        Ok(())
+    }
+
+    pub fn move_crate_9001(&mut self, m: &Move) -> Result<()> {
+        self.check_move(m)?;
+
+        let from_vec = &mut self.stack[m.from_idx];
+        let mut new_append_vec = from_vec.split_off(from_vec.len() - m.count);
+
+        self.stack[m.to_idx].append(&mut new_append_vec);
+
+        Ok(())
+    }
+
+    /// The crate on top of each stack, failing if any stack is empty.
+    pub fn top_crates(&self) -> Result<String> {
+        self.stack
+            .iter()
+            .enumerate()
+            .map(|(i, s)| {
+                s.last()
+                    .with_context(|| format!("Stack {} ends up empty", i + 1))
+            })
+            .collect()
+    }
+}
+
+impl FromStr for Crane {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines: Vec<&str> = s.lines().collect();
+
+        let labels = lines
+            .pop()
+            .ok_or_else(|| ParseError::new(s, s, "a row of stack numbers"))?;
+        let num_stacks = labels.split_whitespace().count();
+
+        let mut stack: Vec<Vec<char>> = vec![Vec::new(); num_stacks];
+
+        for line in lines.iter().rev() {
+            for (idx, ch) in line.char_indices() {
+                if ch.is_alphanumeric() {
+                    let vec_idx = idx / 4;
+                    if vec_idx >= num_stacks {
+                        let found = &line[idx..idx + ch.len_utf8()];
+                        return Err(ParseError::new(s, found, "a crate above a numbered stack"));
+                    }
+
+                    stack[vec_idx].push(ch);
+                }
+            }
+        }
+
+        Ok(Self { stack })
+    }
+}
+
+impl FromStr for Move {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rest = strip_prefix(s, s, "move ")?;
+        let (count, rest) = split_once(s, rest, " from ")?;
+        let (from, to) = split_once(s, rest, " to ")?;
+
+        let parse_stack = |found: &str| -> Result<usize, ParseError> {
+            match parse_value::<usize>(s, found, "a stack number")? {
+                0 => Err(ParseError::new(s, found, "a stack number")),
+                n => Ok(n - 1),
+            }
+        };
+
+        Ok(Self {
+            from_idx: parse_stack(from)?,
+            to_idx: parse_stack(to)?,
+            count: parse_value(s, count, "a crate count")?,
+        })
+    }
+}
+
+/// The starting crate stacks and the rearrangement procedure to run on them.
+#[derive(Debug)]
+pub struct Procedure {
+    pub crane: Crane,
+    pub moves: Vec<Move>,
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Parsed = Procedure;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let (crane_input, moves_input) = split_once(input, input, "\n\n")?;
+
+        let crane = crane_input.parse::<Crane>()?;
+
+        let moves_offset = crane_input.lines().count() + 1;
+        let moves: Vec<Move> = parse_lines(moves_input).map_err(|e| e.offset(moves_offset))?;
+
+        for (m, line) in moves.iter().zip(moves_input.lines()) {
+            if m.from_idx.max(m.to_idx) >= crane.stack.len() {
+                return Err(ParseError::new(
+                    input,
+                    line,
+                    "a move between existing stacks",
+                ));
+            }
+        }
+
+        Ok(Procedure { crane, moves })
+    }
+
+    fn part1(procedure: &Self::Parsed) -> Result<Self::Answer1> {
+        let mut crane = procedure.crane.clone();
+
+        for (i, m) in procedure.moves.iter().enumerate() {
+            crane
+                .move_crate(m)
+                .with_context(|| format!("Could not make move {}", i + 1))?;
+        }
+
+        crane.top_crates()
+    }
+
+    fn part2(procedure: &Self::Parsed) -> Result<Self::Answer2> {
+        let mut crane = procedure.crane.clone();
+
+        for (i, m) in procedure.moves.iter().enumerate() {
+            crane
+                .move_crate_9001(m)
+                .with_context(|| format!("Could not make move {}", i + 1))?;
+        }
+
+        crane.top_crates()
+    }
+}
@@ -0,0 +1,45 @@
+use anyhow::{Context, Result};
+use linked_hash_set::LinkedHashSet;
+
+use super::Solution;
+use crate::parse::ParseError;
+
+/// Returns the number of characters read once the last `marker_len` characters
+/// are all different, or `None` if that never happens.
+pub fn find_marker(msg: &str, marker_len: usize) -> Option<usize> {
+    let mut seen: LinkedHashSet<char> = LinkedHashSet::new();
+    for (i, ch) in msg.chars().enumerate() {
+        if seen.len() < marker_len {
+            while seen.contains(&ch) {
+                seen.pop_front();
+            }
+            seen.insert(ch);
+        }
+
+        if seen.len() == marker_len {
+            return Some(i + 1);
+        }
+    }
+
+    None
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Parsed = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Ok(input.trim_end().to_string())
+    }
+
+    fn part1(input: &Self::Parsed) -> Result<Self::Answer1> {
+        find_marker(input, 4).context("No start-of-packet marker in the datastream")
+    }
+
+    fn part2(input: &Self::Parsed) -> Result<Self::Answer2> {
+        find_marker(input, 14).context("No start-of-message marker in the datastream")
+    }
+}
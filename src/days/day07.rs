@@ -0,0 +1,155 @@
+use anyhow::{bail, Context, Result};
+use std::str::FromStr;
+
+use super::Solution;
+use crate::parse::{parse_lines, parse_value, split_once, ParseError};
+
+/// One line of the terminal session: either a command or a line of `ls` output.
+#[derive(Debug)]
+pub enum TerminalLine {
+    ChangeDir(String),
+    List,
+    Dir(String),
+    File { size: usize, name: String },
+}
+
+impl FromStr for TerminalLine {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(command) = s.strip_prefix("$ ") {
+            if let Some(target_dir) = command.strip_prefix("cd ") {
+                return Ok(TerminalLine::ChangeDir(target_dir.to_string()));
+            } else if command == "ls" {
+                return Ok(TerminalLine::List);
+            }
+
+            return Err(ParseError::new(s, command, "a cd or ls command"));
+        }
+
+        let (info, name) = split_once(s, s, " ")?;
+
+        if info == "dir" {
+            return Ok(TerminalLine::Dir(name.to_string()));
+        }
+
+        Ok(TerminalLine::File {
+            size: parse_value(s, info, "a file size or \"dir\"")?,
+            name: name.to_string(),
+        })
+    }
+}
+
+/// The total size of a directory, including everything nested below it.
+#[derive(Debug)]
+pub struct DirSize {
+    pub name: String,
+    pub size: usize,
+}
+
+/// Replays the terminal output and returns the size of every directory
+/// visited. `input` is the text `lines` were parsed from, one per line, and
+/// is used to point at a `cd ..` that goes above `/`.
+pub fn get_dir_sizes(input: &str, lines: &[TerminalLine]) -> Result<Vec<DirSize>, ParseError> {
+    let mut stack = vec![("/", 0)];
+    let mut total_sizes: Vec<DirSize> = vec![];
+
+    for (text, line) in input.lines().zip(lines) {
+        match line {
+            TerminalLine::ChangeDir(target_dir) => {
+                if target_dir == "/" {
+                    continue;
+                } else if target_dir == ".." {
+                    let (dir, amount) = stack.pop().unwrap();
+
+                    let Some(parent) = stack.last_mut() else {
+                        return Err(ParseError::new(
+                            input,
+                            text,
+                            "a cd .. from below the root directory",
+                        ));
+                    };
+
+                    parent.1 += amount;
+                    total_sizes.push(DirSize {
+                        name: dir.to_string(),
+                        size: amount,
+                    });
+                } else {
+                    stack.push((target_dir, 0));
+                }
+            }
+            TerminalLine::List | TerminalLine::Dir(_) => continue,
+            TerminalLine::File { size, .. } => {
+                // `cd ..` never pops the root, so the stack is never empty.
+                stack.last_mut().unwrap().1 += size;
+            }
+        }
+    }
+
+    while let Some((dir, amount)) = stack.pop() {
+        total_sizes.push(DirSize {
+            name: dir.to_string(),
+            size: amount,
+        });
+
+        if !stack.is_empty() {
+            stack.last_mut().unwrap().1 += amount;
+        }
+    }
+
+    Ok(total_sizes)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Parsed = Vec<DirSize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let lines: Vec<TerminalLine> = parse_lines(input)?;
+
+        get_dir_sizes(input, &lines)
+    }
+
+    fn part1(total_sizes: &Self::Parsed) -> Result<Self::Answer1> {
+        let max_dir_size = 100000;
+
+        let total = total_sizes
+            .iter()
+            .map(|d| d.size)
+            .filter(|size| *size <= max_dir_size)
+            .sum();
+
+        Ok(total)
+    }
+
+    fn part2(total_sizes: &Self::Parsed) -> Result<Self::Answer2> {
+        let total_disk_space = 70000000;
+        let needed_free_space = 30000000;
+
+        // The root is the last directory popped off the stack.
+        let total_used_space: usize = total_sizes.last().unwrap().size;
+
+        if total_used_space > total_disk_space {
+            bail!(
+                "The files take up {} but the disk only holds {}",
+                total_used_space,
+                total_disk_space
+            );
+        }
+
+        let unused_space = total_disk_space - total_used_space;
+
+        let size_to_delete = total_sizes
+            .iter()
+            .filter(|d| unused_space + d.size >= needed_free_space)
+            .map(|d| d.size)
+            .min()
+            .context("No directory frees up enough space")?;
+
+        Ok(size_to_delete)
+    }
+}
@@ -0,0 +1,64 @@
+use anyhow::{Context, Result};
+
+use super::Solution;
+use crate::{
+    grid::{Grid, Point},
+    parse::ParseError,
+};
+
+/// Whether the tree at `p` can be seen from outside the grid, looking along a
+/// row or column.
+pub fn is_tree_visible(grid: &Grid<u8>, p: Point) -> bool {
+    let height = grid[p];
+
+    Point::ORTHOGONAL
+        .iter()
+        .any(|&direction| grid.ray(p, direction).all(|q| grid[q] < height))
+}
+
+pub fn get_scenic_score(grid: &Grid<u8>, p: Point) -> usize {
+    let height = grid[p];
+
+    Point::ORTHOGONAL
+        .iter()
+        .map(|&direction| {
+            let mut count = 0;
+
+            for q in grid.ray(p, direction) {
+                count += 1;
+
+                if grid[q] >= height {
+                    break;
+                }
+            }
+
+            count
+        })
+        .product()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    /// Tree heights, 0 to 9.
+    type Parsed = Grid<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Grid::parse(input.trim_end(), "a digit", |ch| {
+            ch.to_digit(10).map(|height| height as u8)
+        })
+    }
+
+    fn part1(grid: &Self::Parsed) -> Result<Self::Answer1> {
+        Ok(grid.points().filter(|&p| is_tree_visible(grid, p)).count())
+    }
+
+    fn part2(grid: &Self::Parsed) -> Result<Self::Answer2> {
+        grid.points()
+            .map(|p| get_scenic_score(grid, p))
+            .max()
+            .context("The grid has no trees")
+    }
+}
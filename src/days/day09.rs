@@ -1,7 +1,11 @@
+use anyhow::Result;
 use std::{collections::HashSet, str::FromStr};
 
+use super::Solution;
+use crate::parse::{parse_lines, parse_value, split_once, ParseError};
+
 #[derive(Debug)]
-enum Direction {
+pub enum Direction {
     Up,
     Down,
     Left,
@@ -9,18 +13,18 @@ enum Direction {
 }
 
 #[derive(Debug)]
-struct Move {
-    direction: Direction,
-    count: usize,
+pub struct Move {
+    pub direction: Direction,
+    pub count: usize,
 }
 
 impl FromStr for Move {
-    type Err = anyhow::Error;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (dir, count_str) = s.split_once(" ").unwrap();
+        let (dir, count_str) = split_once(s, s, " ")?;
 
-        let count = count_str.parse::<usize>().unwrap();
+        let count = parse_value(s, count_str, "a step count")?;
 
         match dir {
             "U" => Ok(Self {
@@ -39,12 +43,12 @@ impl FromStr for Move {
                 direction: Direction::Right,
                 count,
             }),
-            _ => Err(anyhow::Error::msg("Invalid move")),
+            _ => Err(ParseError::new(s, dir, "one of U, D, L or R")),
         }
     }
 }
 
-fn move_pos_once(pos: &mut (isize, isize), direction: &Direction) {
+pub fn move_pos_once(pos: &mut (isize, isize), direction: &Direction) {
     match direction {
         Direction::Up => {
             pos.0 -= 1;
@@ -61,14 +65,14 @@ fn move_pos_once(pos: &mut (isize, isize), direction: &Direction) {
     }
 }
 
-fn are_diagonally_touching(pos1: &(isize, isize), pos2: &(isize, isize)) -> bool {
-    return &(pos1.0 - 1, pos1.1 - 1) == pos2
+pub fn are_diagonally_touching(pos1: &(isize, isize), pos2: &(isize, isize)) -> bool {
+    &(pos1.0 - 1, pos1.1 - 1) == pos2
         || &(pos1.0 - 1, pos1.1 + 1) == pos2
         || &(pos1.0 + 1, pos1.1 - 1) == pos2
-        || &(pos1.0 + 1, pos1.1 + 1) == pos2;
+        || &(pos1.0 + 1, pos1.1 + 1) == pos2
 }
 
-fn move_tail(relative_head_pos: &(isize, isize), tail_pos: &mut (isize, isize)) {
+pub fn move_tail(relative_head_pos: &(isize, isize), tail_pos: &mut (isize, isize)) {
     if relative_head_pos.0 == tail_pos.0 {
         if tail_pos.1 - relative_head_pos.1 == 2 {
             move_pos_once(tail_pos, &Direction::Left);
@@ -98,7 +102,7 @@ fn move_tail(relative_head_pos: &(isize, isize), tail_pos: &mut (isize, isize))
     }
 }
 
-fn move_rope(movers: &mut Vec<(isize, isize)>, head_move_direction: &Direction) {
+pub fn move_rope(movers: &mut Vec<(isize, isize)>, head_move_direction: &Direction) {
     let first_head = &mut movers[0];
     move_pos_once(first_head, head_move_direction);
     for i in 1..movers.len() {
@@ -109,12 +113,9 @@ fn move_rope(movers: &mut Vec<(isize, isize)>, head_move_direction: &Direction)
     }
 }
 
-fn main() {
-    let input = include_str!("../inputs/day9.txt");
-
-    let move_list: Vec<Move> = input.lines().map(|l| l.parse::<Move>().unwrap()).collect();
-
-    let mut rope: Vec<(isize, isize)> = vec![(0, 0); 10];
+/// Simulates a rope of `knots` knots and returns how many positions its tail visits.
+pub fn count_tail_positions(move_list: &[Move], knots: usize) -> usize {
+    let mut rope: Vec<(isize, isize)> = vec![(0, 0); knots];
 
     let mut tail_visited: HashSet<(isize, isize)> = HashSet::new();
 
@@ -126,7 +127,25 @@ fn main() {
         }
     }
 
-    let visited_total = tail_visited.len();
+    tail_visited.len()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Parsed = Vec<Move>;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    println!("{}", visited_total);
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(move_list: &Self::Parsed) -> Result<Self::Answer1> {
+        Ok(count_tail_positions(move_list, 2))
+    }
+
+    fn part2(move_list: &Self::Parsed) -> Result<Self::Answer2> {
+        Ok(count_tail_positions(move_list, 10))
+    }
 }
@@ -0,0 +1,167 @@
+use std::str::FromStr;
+
+use anyhow::Result;
+
+use super::Solution;
+use crate::parse::{parse_lines, parse_value, ParseError};
+
+#[derive(Debug)]
+pub enum CommandName {
+    Noop,
+    Addx,
+}
+
+#[derive(Debug)]
+pub struct Command {
+    pub name: CommandName,
+    pub arg: Option<isize>,
+}
+
+impl FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(arg) = s.strip_prefix("addx ") {
+            Ok(Self {
+                name: CommandName::Addx,
+                arg: Some(parse_value(s, arg, "an integer")?),
+            })
+        } else if s == "noop" {
+            Ok(Self {
+                name: CommandName::Noop,
+                arg: None,
+            })
+        } else {
+            Err(ParseError::new(s, s, "noop or addx"))
+        }
+    }
+}
+
+pub fn get_signal_strength(commands: &[Command]) -> isize {
+    let desired_cycle_nums: Vec<isize> = vec![20, 60, 100, 140, 180, 220];
+
+    let mut x: isize = 1;
+    let mut cycle_num: isize = 1;
+    let mut total_strength = 0;
+
+    let mut command_iter = commands.iter();
+    let mut curr_command = command_iter.next();
+
+    let mut tick: u8 = 0;
+    while curr_command.is_some() {
+        if desired_cycle_nums.contains(&cycle_num) {
+            total_strength += cycle_num * x;
+        }
+
+        let c = curr_command.unwrap();
+
+        if tick > 0 {
+            x += c.arg.unwrap();
+            curr_command = command_iter.next();
+
+            tick = 0;
+        } else {
+            match c.name {
+                CommandName::Noop => {
+                    tick = 0;
+                    curr_command = command_iter.next();
+                }
+                CommandName::Addx => {
+                    tick = 1;
+                }
+            }
+        }
+
+        cycle_num += 1;
+    }
+
+    total_strength
+}
+
+const CRT_WIDTH: usize = 40;
+
+/// Draws the CRT a row of 40 pixels at a time. Programs longer than the
+/// screen's 240 cycles carry on into extra rows.
+pub fn render_crt(commands: &[Command]) -> String {
+    let mut result = String::from("");
+    let mut sprite_pos: Vec<bool> = vec![false; CRT_WIDTH];
+
+    sprite_pos[0..3].fill(true);
+
+    let mut x: isize = 1;
+    let mut cycle_num: usize = 1;
+    let mut tick: u8 = 0;
+
+    let mut command_iter = commands.iter();
+    let mut curr_command = command_iter.next();
+
+    while curr_command.is_some() {
+        if sprite_pos[(cycle_num - 1) % CRT_WIDTH] {
+            result += "#";
+        } else {
+            result += ".";
+        }
+
+        let c = curr_command.unwrap();
+
+        if tick > 0 {
+            sprite_pos.fill(false);
+
+            x += c.arg.unwrap();
+
+            if x > 0 && x - 1 < CRT_WIDTH as isize {
+                sprite_pos[(x - 1) as usize] = true;
+            }
+
+            if (0..CRT_WIDTH as isize).contains(&x) {
+                sprite_pos[x as usize] = true;
+            }
+
+            if x + 1 >= 0 && x + 1 < CRT_WIDTH as isize {
+                sprite_pos[(x + 1) as usize] = true;
+            }
+
+            curr_command = command_iter.next();
+
+            tick = 0;
+        } else {
+            match c.name {
+                CommandName::Noop => {
+                    tick = 0;
+                    curr_command = command_iter.next();
+                }
+                CommandName::Addx => {
+                    tick = 1;
+                }
+            }
+        }
+
+        if cycle_num.is_multiple_of(CRT_WIDTH) {
+            result += "\n";
+        }
+
+        cycle_num += 1;
+    }
+
+    result
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed = Vec<Command>;
+    type Answer1 = isize;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(commands: &Self::Parsed) -> Result<Self::Answer1> {
+        Ok(get_signal_strength(commands))
+    }
+
+    fn part2(commands: &Self::Parsed) -> Result<Self::Answer2> {
+        Ok(render_crt(commands))
+    }
+}
@@ -0,0 +1,179 @@
+use anyhow::Result;
+use std::str::FromStr;
+
+use super::Solution;
+use crate::parse::{parse_blocks, parse_value, split_once, strip_prefix, ParseError};
+
+#[derive(Debug, Clone)]
+pub enum Operand {
+    Add,
+    Multiply,
+}
+
+#[derive(Debug, Clone)]
+pub struct Operation {
+    pub operand: Operand,
+    pub rhs: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    pub items: Vec<usize>,
+    pub operation: Operation,
+    pub divisible_by_test: usize,
+    pub true_condition_monkey: usize,
+    pub false_condition_monkey: usize,
+}
+
+impl FromStr for Monkey {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines().map(str::trim);
+
+        let mut next_line = |prefix: &str| -> Result<&str, ParseError> {
+            let line = lines
+                .next()
+                .ok_or_else(|| ParseError::new(s, &s[s.len()..], format!("{:?}", prefix)))?;
+
+            strip_prefix(s, line, prefix)
+        };
+
+        next_line("Monkey ")?;
+
+        let item_list_str = next_line("Starting items: ")?;
+        let starting_items = if item_list_str.is_empty() {
+            vec![]
+        } else {
+            item_list_str
+                .split(", ")
+                .map(|item| parse_value(s, item, "a worry level"))
+                .collect::<Result<Vec<usize>, _>>()?
+        };
+
+        let expr = next_line("Operation: new = old ")?;
+        let (operand_str, rhs_str) = split_once(s, expr, " ")?;
+
+        let operand: Operand = match operand_str {
+            "+" => Operand::Add,
+            "*" => Operand::Multiply,
+            _ => return Err(ParseError::new(s, operand_str, "+ or *")),
+        };
+
+        let rhs = if rhs_str == "old" {
+            None
+        } else {
+            Some(parse_value(s, rhs_str, "a number or \"old\"")?)
+        };
+
+        let test_str = next_line("Test: divisible by ")?;
+        let divisible_by_test = match parse_value(s, test_str, "a divisor")? {
+            0 => return Err(ParseError::new(s, test_str, "a non-zero divisor")),
+            n => n,
+        };
+
+        let true_str = next_line("If true: throw to monkey ")?;
+        let true_condition_monkey = parse_value(s, true_str, "a monkey number")?;
+
+        let false_str = next_line("If false: throw to monkey ")?;
+        let false_condition_monkey = parse_value(s, false_str, "a monkey number")?;
+
+        Ok(Self {
+            items: starting_items,
+            operation: Operation { operand, rhs },
+            divisible_by_test,
+            true_condition_monkey,
+            false_condition_monkey,
+        })
+    }
+}
+
+/// How worry levels are kept manageable after each inspection.
+#[derive(Debug, Clone, Copy)]
+pub enum Relief {
+    /// Worry is divided by three once the monkey loses interest.
+    DivideByThree,
+    /// Worry is reduced modulo the product of every monkey's test divisor.
+    Modulo(usize),
+}
+
+/// Plays `num_rounds` rounds of keep away and returns the level of monkey business.
+pub fn play(mut monkeys: Vec<Monkey>, num_rounds: usize, relief: Relief) -> usize {
+    let mut inspections: Vec<usize> = vec![0; monkeys.len()];
+
+    for _ in 0..num_rounds {
+        for monkey_idx in 0..monkeys.len() {
+            let items = std::mem::take(&mut monkeys[monkey_idx].items);
+            inspections[monkey_idx] += items.len();
+            for mut item in items {
+                match monkeys[monkey_idx].operation.operand {
+                    Operand::Add => {
+                        if let Some(rhs) = monkeys[monkey_idx].operation.rhs {
+                            item += rhs;
+                        } else {
+                            item += item;
+                        }
+                    }
+                    Operand::Multiply => {
+                        if let Some(rhs) = monkeys[monkey_idx].operation.rhs {
+                            item *= rhs;
+                        } else {
+                            item *= item;
+                        }
+                    }
+                }
+
+                match relief {
+                    Relief::DivideByThree => item /= 3,
+                    Relief::Modulo(lcm) => item %= lcm,
+                }
+
+                if item % monkeys[monkey_idx].divisible_by_test == 0 {
+                    let dst_monkey_idx = monkeys[monkey_idx].true_condition_monkey;
+                    monkeys[dst_monkey_idx].items.push(item);
+                } else {
+                    let dst_monkey_idx = monkeys[monkey_idx].false_condition_monkey;
+                    monkeys[dst_monkey_idx].items.push(item);
+                }
+            }
+        }
+    }
+
+    inspections.sort_by(|a, b| b.cmp(a));
+
+    inspections.iter().take(2).product()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed = Vec<Monkey>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let monkeys: Vec<Monkey> = parse_blocks(input)?;
+
+        for (monkey, block) in monkeys.iter().zip(input.split("\n\n")) {
+            let targets = [monkey.true_condition_monkey, monkey.false_condition_monkey];
+
+            for (target, line) in targets.into_iter().zip(block.lines().skip(4)) {
+                if target >= monkeys.len() {
+                    return Err(ParseError::new(input, line.trim(), "a monkey that exists"));
+                }
+            }
+        }
+
+        Ok(monkeys)
+    }
+
+    fn part1(monkeys: &Self::Parsed) -> Result<Self::Answer1> {
+        Ok(play(monkeys.clone(), 20, Relief::DivideByThree))
+    }
+
+    fn part2(monkeys: &Self::Parsed) -> Result<Self::Answer2> {
+        let lcm: usize = monkeys.iter().map(|m| m.divisible_by_test).product();
+
+        Ok(play(monkeys.clone(), 10000, Relief::Modulo(lcm)))
+    }
+}
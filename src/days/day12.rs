@@ -0,0 +1,89 @@
+use anyhow::{Context, Result};
+
+use super::Solution;
+use crate::{
+    grid::{Grid, Point},
+    parse::ParseError,
+    search,
+};
+
+pub static ALPHABET: &str = "abcdefghijklmnopqrstuvwxyz";
+
+/// The squares that can be stepped to from `p`: at most one higher.
+pub fn get_climbable_neighbors(
+    grid: &Grid<usize>,
+    p: Point,
+) -> impl Iterator<Item = (Point, usize)> + '_ {
+    grid.neighbors4(p)
+        .filter(move |&n| grid[n] <= grid[p] + 1)
+        .map(|n| (n, 1))
+}
+
+/// The squares that could have been stepped from to reach `p`.
+pub fn get_descending_neighbors(
+    grid: &Grid<usize>,
+    p: Point,
+) -> impl Iterator<Item = (Point, usize)> + '_ {
+    grid.neighbors4(p)
+        .filter(move |&n| grid[p] <= grid[n] + 1)
+        .map(|n| (n, 1))
+}
+
+/// Returns the number of steps from `start` to `end`, or `None` if `end` can't be reached.
+pub fn get_shortest_path(grid: &Grid<usize>, start: Point, end: Point) -> Option<usize> {
+    let climb = |p: &Point| get_climbable_neighbors(grid, *p);
+
+    search::bfs(&climb, start, |&p| p == end).map(|path| path.cost)
+}
+
+#[derive(Debug)]
+pub struct Heightmap {
+    pub grid: Grid<usize>,
+    pub start: Point,
+    pub end: Point,
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Parsed = Heightmap;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let squares = Grid::parse(input.trim_end(), "a height a-z, S or E", |ch| {
+            (ch == 'S' || ch == 'E' || ALPHABET.contains(ch)).then_some(ch)
+        })?;
+
+        let start = squares
+            .position(|&ch| ch == 'S')
+            .ok_or_else(|| ParseError::new(input, input, "a start square S"))?;
+        let end = squares
+            .position(|&ch| ch == 'E')
+            .ok_or_else(|| ParseError::new(input, input, "a best signal square E"))?;
+
+        let grid = squares.map(|&ch| match ch {
+            'S' => 0,
+            'E' => 25,
+            _ => ALPHABET.find(ch).unwrap(),
+        });
+
+        Ok(Heightmap { grid, start, end })
+    }
+
+    fn part1(heightmap: &Self::Parsed) -> Result<Self::Answer1> {
+        get_shortest_path(&heightmap.grid, heightmap.start, heightmap.end)
+            .context("The best signal can't be reached from the start")
+    }
+
+    /// Walks backwards from the end, so the first lowest square found is the
+    /// closest one.
+    fn part2(heightmap: &Self::Parsed) -> Result<Self::Answer2> {
+        let grid = &heightmap.grid;
+        let descend = |p: &Point| get_descending_neighbors(grid, *p);
+
+        search::bfs(&descend, heightmap.end, |&p| grid[p] == 0)
+            .map(|path| path.cost)
+            .context("The best signal can't be reached from any lowest square")
+    }
+}
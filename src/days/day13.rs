@@ -0,0 +1,185 @@
+use anyhow::Result;
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use super::Solution;
+use crate::parse::{parse_blocks, parse_value, ParseError};
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Data {
+    Int(u32),
+    List(Vec<Data>),
+}
+
+impl Ord for Data {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Data::Int(lhs), Data::Int(rhs)) => lhs.cmp(rhs),
+            (Data::Int(lhs), Data::List(_)) => {
+                let lhs_list = Data::List(vec![Data::Int(*lhs)]);
+
+                lhs_list.cmp(other)
+            }
+            (Data::List(_), Data::Int(rhs)) => {
+                let rhs_list = Data::List(vec![Data::Int(*rhs)]);
+
+                self.cmp(&rhs_list)
+            }
+            (Data::List(lhs), Data::List(rhs)) => {
+                let mut lhs_iter = lhs.iter();
+                let mut rhs_iter = rhs.iter();
+
+                loop {
+                    match (lhs_iter.next(), rhs_iter.next()) {
+                        (Some(l), Some(r)) => {
+                            let ord = l.cmp(r);
+                            if ord != Ordering::Equal {
+                                return ord;
+                            }
+                        }
+                        (Some(_), None) => return Ordering::Greater,
+                        (None, Some(_)) => return Ordering::Less,
+                        (None, None) => return Ordering::Equal,
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl PartialOrd for Data {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl FromStr for Data {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (data, rest) = parse_data(s, s.trim_end())?;
+
+        if !rest.is_empty() {
+            return Err(ParseError::new(s, rest, "the end of the packet"));
+        }
+
+        Ok(data)
+    }
+}
+
+fn first_char(s: &str) -> &str {
+    &s[..s.chars().next().map_or(0, char::len_utf8)]
+}
+
+/// Parses one integer or list from the front of `s`, returning it with the unparsed rest.
+fn parse_data<'a>(source: &str, s: &'a str) -> Result<(Data, &'a str), ParseError> {
+    if let Some(mut rest) = s.strip_prefix('[') {
+        let mut list = Vec::new();
+
+        if let Some(after) = rest.strip_prefix(']') {
+            return Ok((Data::List(list), after));
+        }
+
+        loop {
+            let (item, after) = parse_data(source, rest)?;
+            list.push(item);
+
+            if let Some(after) = after.strip_prefix(',') {
+                rest = after;
+            } else if let Some(after) = after.strip_prefix(']') {
+                return Ok((Data::List(list), after));
+            } else {
+                return Err(ParseError::new(source, first_char(after), "',' or ']'"));
+            }
+        }
+    }
+
+    let digits_end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+
+    if digits_end == 0 {
+        return Err(ParseError::new(source, first_char(s), "'[' or an integer"));
+    }
+
+    let value = parse_value(source, &s[..digits_end], "an integer")?;
+
+    Ok((Data::Int(value), &s[digits_end..]))
+}
+
+/// One blank-line separated pair of packets.
+#[derive(Debug)]
+struct Pair(Data, Data);
+
+impl FromStr for Pair {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<&str> = s.lines().collect();
+
+        match lines.as_slice() {
+            [left, right] => Ok(Pair(
+                left.parse()?,
+                right.parse().map_err(|e: ParseError| e.offset(1))?,
+            )),
+            [_, _, extra, ..] => Err(ParseError::new(s, extra, "a blank line after a pair")),
+            _ => Err(ParseError::new(s, s, "a pair of packets")),
+        }
+    }
+}
+
+pub fn divider_packet(n: u32) -> Data {
+    Data::List(vec![Data::List(vec![Data::Int(n)])])
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Parsed = Vec<(Data, Data)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let pairs: Vec<Pair> = parse_blocks(input)?;
+
+        Ok(pairs
+            .into_iter()
+            .map(|Pair(left, right)| (left, right))
+            .collect())
+    }
+
+    fn part1(pairs: &Self::Parsed) -> Result<Self::Answer1> {
+        let sum: usize = pairs
+            .iter()
+            .enumerate()
+            .filter_map(|(i, (left, right))| {
+                if left < right {
+                    return Some(i + 1);
+                }
+
+                None
+            })
+            .sum();
+
+        Ok(sum)
+    }
+
+    fn part2(pairs: &Self::Parsed) -> Result<Self::Answer2> {
+        let mut packets: Vec<&Data> = pairs
+            .iter()
+            .flat_map(|(left, right)| [left, right])
+            .collect();
+
+        let first_divider = divider_packet(2);
+        let second_divider = divider_packet(6);
+
+        packets.push(&first_divider);
+        packets.push(&second_divider);
+
+        packets.sort();
+
+        let i = packets.iter().position(|p| **p == first_divider).unwrap();
+
+        let j = packets.iter().position(|p| **p == second_divider).unwrap();
+
+        Ok((i + 1) * (j + 1))
+    }
+}
@@ -0,0 +1,666 @@
+use anyhow::{bail, Context, Result};
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Display},
+    rc::Rc,
+    str::FromStr,
+};
+
+use super::Solution;
+use crate::{
+    grid::Point,
+    params::Params,
+    parse::{parse_value, split_once, ParseError},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Item {
+    Rock,
+    SandSource,
+    Air,
+    Sand,
+}
+
+/// A cave of unbounded size. Only rock and sand are stored, by position, so
+/// sand can spread as far sideways as it likes. With a floor, everything from
+/// the floor's depth down is solid rock.
+#[derive(Debug, Clone)]
+pub struct Cave {
+    cells: HashMap<Point, Item>,
+    rules: Rc<dyn Rules>,
+    pub sand_source: Point,
+    /// The deepest rock in the scan.
+    pub max_y: isize,
+    /// The depth of the floor, if there is one.
+    pub floor: Option<isize>,
+}
+
+/// When sand stops pouring into the cave.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Termination {
+    /// When a grain falls past the deepest rock.
+    Void,
+    /// When sand piled on a floor `depth` below the deepest rock blocks the
+    /// source.
+    Floor { depth: isize },
+}
+
+/// How sand falls. Each grain tries the same moves, in order, wherever it
+/// is. Every move takes it one row down, so no grain falls forever.
+pub trait Rules: Debug {
+    /// How far sideways a grain tries to move as it falls a row, in order of
+    /// preference.
+    fn steps(&self) -> &[isize];
+
+    fn termination(&self) -> Termination;
+}
+
+/// Part 1: sand falls straight down, then down to the left, then down to
+/// the right, until it falls into the void.
+#[derive(Debug, Clone, Copy)]
+pub struct Void;
+
+/// Part 2: sand falls like in part 1, onto a floor two below the deepest
+/// rock.
+#[derive(Debug, Clone, Copy)]
+pub struct Floor;
+
+impl Rules for Void {
+    fn steps(&self) -> &[isize] {
+        &FALL_STEPS
+    }
+
+    fn termination(&self) -> Termination {
+        Termination::Void
+    }
+}
+
+impl Rules for Floor {
+    fn steps(&self) -> &[isize] {
+        &FALL_STEPS
+    }
+
+    fn termination(&self) -> Termination {
+        Termination::Floor { depth: FLOOR_DEPTH }
+    }
+}
+
+/// Sand that falls in its own way, into the void or onto the floor.
+#[derive(Debug, Clone)]
+pub struct Variant {
+    steps: Vec<isize>,
+    termination: Termination,
+}
+
+impl Variant {
+    /// Sand that moves sideways by each of `steps`, in order, as it falls a
+    /// row.
+    pub fn new(steps: &[isize], termination: Termination) -> Self {
+        Self {
+            steps: steps.to_vec(),
+            termination,
+        }
+    }
+}
+
+impl Rules for Variant {
+    fn steps(&self) -> &[isize] {
+        &self.steps
+    }
+
+    fn termination(&self) -> Termination {
+        self.termination
+    }
+}
+
+/// Parses an `x,y` point on a rock path, reporting errors by their position
+/// in `source`.
+fn parse_point(source: &str, s: &str) -> Result<Point, ParseError> {
+    let (x_str, y_str) = split_once(source, s, ",")?;
+
+    Ok(Point::new(
+        parse_value(source, x_str, "an x coordinate")?,
+        parse_value(source, y_str, "a y coordinate")?,
+    ))
+}
+
+impl Display for Cave {
+    /// Draws everything from the sand source to the furthest rock or sand,
+    /// and the floor under it.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (min, max) = self.bounds();
+
+        write!(f, "{}", self.render_ascii(min, max, None))
+    }
+}
+
+/// What happened to a grain of sand after one step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fall {
+    To(Point),
+    Rest,
+    /// It fell past the deepest rock, with no floor to stop it.
+    Void,
+}
+
+/// Where the sand pours in from.
+pub const SAND_SOURCE: Point = Point::new(500, 0);
+
+/// How far below the deepest rock the floor is.
+const FLOOR_DEPTH: isize = 2;
+
+/// Which way a grain of sand tries to move as it falls, in order of
+/// preference: straight down, then down to the left, then down to the right.
+const FALL_STEPS: [isize; 3] = [0, -1, 1];
+
+/// The furthest sideways a grain may move in one step under custom rules.
+/// Sand spreads this far for every row it falls, so much bigger steps would
+/// overflow the coordinates.
+pub const MAX_STEP: isize = 1000;
+
+const AIR_COLOR: [u8; 3] = [20, 20, 30];
+const ROCK_COLOR: [u8; 3] = [110, 100, 90];
+const SOURCE_COLOR: [u8; 3] = [230, 40, 40];
+const SAND_COLOR: [u8; 3] = [220, 180, 90];
+const FALLING_COLOR: [u8; 3] = [255, 240, 160];
+
+impl Cave {
+    /// Builds a cave from the rock paths in the scan, with sand falling by
+    /// `rules`.
+    pub fn from_paths(lines: &[Vec<Point>], rules: Rc<dyn Rules>) -> Self {
+        let max_y = lines.iter().flatten().map(|p| p.y).max().unwrap();
+        let floor = match rules.termination() {
+            Termination::Void => None,
+            Termination::Floor { depth } => Some(max_y + depth),
+        };
+
+        let mut cave = Self {
+            cells: HashMap::new(),
+            rules,
+            sand_source: SAND_SOURCE,
+            max_y,
+            floor,
+        };
+
+        cave.fill(lines);
+
+        cave
+    }
+
+    pub fn fill(&mut self, lines: &[Vec<Point>]) {
+        for line in lines {
+            for pair in line.windows(2) {
+                let step = Point::new(
+                    (pair[1].x - pair[0].x).signum(),
+                    (pair[1].y - pair[0].y).signum(),
+                );
+
+                let mut p = pair[0];
+                self.cells.insert(p, Item::Rock);
+
+                while p != pair[1] {
+                    p = p + step;
+                    self.cells.insert(p, Item::Rock);
+                }
+            }
+
+            if let [p] = line.as_slice() {
+                self.cells.insert(*p, Item::Rock);
+            }
+        }
+    }
+
+    pub fn get(&self, p: Point) -> Item {
+        if let Some(&item) = self.cells.get(&p) {
+            return item;
+        }
+
+        match self.floor {
+            Some(floor) if p.y >= floor => Item::Rock,
+            _ if p == self.sand_source => Item::SandSource,
+            _ => Item::Air,
+        }
+    }
+
+    /// The corners of the smallest box holding the sand source and every rock
+    /// and grain of sand, including the floor's row if there is one.
+    pub fn bounds(&self) -> (Point, Point) {
+        let mut min = self.sand_source;
+        let mut max = self.sand_source;
+
+        for p in self.cells.keys() {
+            min = Point::new(min.x.min(p.x), min.y.min(p.y));
+            max = Point::new(max.x.max(p.x), max.y.max(p.y));
+        }
+
+        if let Some(floor) = self.floor {
+            max.y = floor;
+        }
+
+        (min, max)
+    }
+
+    /// The box every grain that comes to rest ends up in. Without a floor
+    /// that's under the rock, so it's the rock's bounds with room either
+    /// side to watch grains fall past. With one, it's the triangle under the
+    /// source down to the floor.
+    pub fn viewport(&self) -> (Point, Point) {
+        let (min, max) = self.bounds();
+        let spread = self.spread();
+
+        match self.floor {
+            Some(floor) => {
+                let width = spread * (floor - 1 - self.sand_source.y);
+
+                (
+                    Point::new(min.x.min(self.sand_source.x - width), min.y),
+                    Point::new(max.x.max(self.sand_source.x + width), floor),
+                )
+            }
+            None => (min - Point::new(spread, 0), max + Point::new(spread, 0)),
+        }
+    }
+
+    /// The furthest a grain can move sideways as it falls one row.
+    fn spread(&self) -> isize {
+        self.rules
+            .steps()
+            .iter()
+            .map(|dx| dx.abs())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The first open spot a grain of sand at `p` can fall to, if any.
+    fn next_position(&self, p: Point) -> Option<Point> {
+        self.rules
+            .steps()
+            .iter()
+            .map(|&dx| p + Point::new(dx, 1))
+            .find(|&n| self.get(n) == Item::Air)
+    }
+
+    /// Moves a grain of sand at `p` one step, leaving it in the cave if it
+    /// comes to rest there.
+    pub fn fall(&mut self, sand: Point) -> Fall {
+        match self.next_position(sand) {
+            Some(next) if self.floor.is_none() && next.y > self.max_y => Fall::Void,
+            Some(next) => Fall::To(next),
+            None => {
+                self.cells.insert(sand, Item::Sand);
+                Fall::Rest
+            }
+        }
+    }
+
+    /// Drops one grain of sand from the source and returns where it came to
+    /// rest, or `None` if it fell past the deepest rock with no floor to
+    /// stop it.
+    fn drop_grain(&mut self) -> Option<Point> {
+        let mut sand = self.sand_source;
+
+        loop {
+            match self.fall(sand) {
+                Fall::To(next) => sand = next,
+                Fall::Rest => return Some(sand),
+                Fall::Void => return None,
+            }
+        }
+    }
+
+    /// Drops grains one at a time until one falls into the void or the
+    /// source is blocked, and returns how many came to rest.
+    pub fn simulate(&mut self) -> usize {
+        let mut rested = 0;
+
+        while let Some(sand) = self.drop_grain() {
+            rested += 1;
+
+            if sand == self.sand_source {
+                break;
+            }
+        }
+
+        rested
+    }
+
+    /// Does the same as [`simulate`](Self::simulate), but keeps the path the
+    /// last grain fell along. Every grain follows the one before it until
+    /// the spot where that one came to rest, so the next grain can start
+    /// from the spot just before it instead of from the source.
+    pub fn simulate_along_path(&mut self) -> usize {
+        let mut path = vec![self.sand_source];
+        let mut rested = 0;
+
+        while let Some(&sand) = path.last() {
+            match self.fall(sand) {
+                Fall::To(next) => path.push(next),
+                Fall::Rest => {
+                    rested += 1;
+                    path.pop();
+                }
+                Fall::Void => break,
+            }
+        }
+
+        rested
+    }
+
+    /// How many grains come to rest before the source is blocked, without
+    /// dropping any. Sand ends up everywhere it can reach, which is each spot
+    /// in the triangle under the source that isn't rock and that sand can
+    /// move to from a spot in the row above. The triangle is filled a row at
+    /// a time down to the floor. Returns `None` without a floor, since then
+    /// sand falls into the void before the triangle is full.
+    pub fn flood_fill(&self) -> Option<usize> {
+        let floor = self.floor?;
+        let source = self.sand_source;
+        let steps = self.rules.steps();
+        let spread = self.spread();
+
+        // Row `y` covers the spots up to `spread` further out on each side
+        // than the row above.
+        let mut row = vec![true];
+        let mut filled = 1;
+
+        for y in source.y + 1..floor {
+            let left = source.x - spread * (y - source.y);
+            let above = |i: isize| i >= 0 && row.get(i as usize).copied().unwrap_or(false);
+
+            let next: Vec<bool> = (0..row.len() as isize + 2 * spread)
+                .map(|i| {
+                    self.get(Point::new(left + i, y)) != Item::Rock
+                        && steps.iter().any(|dx| above(i - spread - dx))
+                })
+                .collect();
+
+            filled += next.iter().filter(|&&sand| sand).count();
+            row = next;
+        }
+
+        Some(filled)
+    }
+
+    /// Draws the box from `min` to `max` in the puzzle's style, with the
+    /// `falling` grain, if any, drawn as sand.
+    pub fn render_ascii(&self, min: Point, max: Point, falling: Option<Point>) -> String {
+        let mut s = String::new();
+
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                let p = Point::new(x, y);
+
+                match self.get(p) {
+                    _ if Some(p) == falling => s += "o ",
+                    Item::Rock => s += "# ",
+                    Item::SandSource => s += "+ ",
+                    Item::Air => s += ". ",
+                    Item::Sand => s += "o ",
+                }
+            }
+            s += "\n"
+        }
+
+        s
+    }
+
+    /// Draws the box from `min` to `max` as a PPM image, with each spot a
+    /// block of `scale` by `scale` pixels.
+    pub fn render_ppm(
+        &self,
+        min: Point,
+        max: Point,
+        scale: usize,
+        falling: Option<Point>,
+    ) -> Vec<u8> {
+        let width = max.x.abs_diff(min.x) + 1;
+        let height = max.y.abs_diff(min.y) + 1;
+        let mut image = format!("P6\n{} {}\n255\n", width * scale, height * scale).into_bytes();
+
+        for y in min.y..=max.y {
+            let row: Vec<u8> = (min.x..=max.x)
+                .flat_map(|x| {
+                    let p = Point::new(x, y);
+                    let color = match self.get(p) {
+                        _ if Some(p) == falling => FALLING_COLOR,
+                        Item::Rock => ROCK_COLOR,
+                        Item::SandSource => SOURCE_COLOR,
+                        Item::Air => AIR_COLOR,
+                        Item::Sand => SAND_COLOR,
+                    };
+
+                    color.repeat(scale)
+                })
+                .collect();
+
+            for _ in 0..scale {
+                image.extend(&row);
+            }
+        }
+
+        image
+    }
+}
+
+/// How the sand is dropped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Method {
+    /// [`Cave::simulate`], with every grain falling from the source.
+    Simulate,
+    /// [`Cave::simulate_along_path`].
+    #[default]
+    Path,
+    /// [`Cave::flood_fill`], which only works for part 2.
+    Flood,
+    /// Every method that works for the part, failing if they disagree.
+    Check,
+}
+
+impl FromStr for Method {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "simulate" => Ok(Method::Simulate),
+            "path" => Ok(Method::Path),
+            "flood" => Ok(Method::Flood),
+            "check" => Ok(Method::Check),
+            _ => bail!("expected simulate, path, flood or check"),
+        }
+    }
+}
+
+/// The order grains try to fall in, set by the `rules` parameter.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Physics {
+    /// [`Void`] for part 1 and [`Floor`] for part 2.
+    #[default]
+    Standard,
+    /// Down to the left, then down to the right, then straight down.
+    DiagonalFirst,
+    /// Down with the wind, blowing by this much, then straight down, then
+    /// down against the wind.
+    Wind(isize),
+    /// Down and sideways by each step, in order.
+    Steps(Vec<isize>),
+}
+
+impl FromStr for Physics {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "standard" => Ok(Physics::Standard),
+            "diagonal-first" => Ok(Physics::DiagonalFirst),
+            "wind-left" => Ok(Physics::Wind(-1)),
+            "wind-right" => Ok(Physics::Wind(1)),
+            _ => {
+                let steps = s
+                    .split(',')
+                    .map(str::parse)
+                    .collect::<Result<Vec<isize>, _>>()
+                    .ok()
+                    .context(
+                        "expected standard, diagonal-first, wind-left, wind-right \
+                         or sideways steps such as 0,-1,1, each at most 1000 either way",
+                    )?;
+
+                if let Some(step) = steps.iter().find(|step| step.abs() > MAX_STEP) {
+                    bail!(
+                        "expected sideways steps of at most {} either way, not {}",
+                        MAX_STEP,
+                        step
+                    );
+                }
+
+                Ok(Physics::Steps(steps))
+            }
+        }
+    }
+}
+
+impl Physics {
+    /// The rules for part 1 or, with a floor, part 2.
+    pub fn rules(&self, floor: bool) -> Rc<dyn Rules> {
+        let termination = if floor {
+            Termination::Floor { depth: FLOOR_DEPTH }
+        } else {
+            Termination::Void
+        };
+
+        match self {
+            Physics::Standard if floor => Rc::new(Floor),
+            Physics::Standard => Rc::new(Void),
+            Physics::DiagonalFirst => Rc::new(Variant::new(&[-1, 1, 0], termination)),
+            &Physics::Wind(dx) => Rc::new(Variant::new(&[dx, 0, -dx], termination)),
+            Physics::Steps(steps) => Rc::new(Variant::new(steps, termination)),
+        }
+    }
+}
+
+/// The rock paths from the scan, in absolute coordinates, and how to drop
+/// sand onto them.
+#[derive(Debug)]
+pub struct Scan {
+    pub paths: Vec<Vec<Point>>,
+    pub method: Method,
+    pub physics: Physics,
+}
+
+impl Scan {
+    /// The cave for part 1 or, with a floor, part 2.
+    pub fn cave(&self, floor: bool) -> Cave {
+        Cave::from_paths(&self.paths, self.physics.rules(floor))
+    }
+
+    /// How many grains of sand come to rest, with or without a floor.
+    pub fn count_sand(&self, floor: bool) -> Result<usize> {
+        let cave = self.cave(floor);
+
+        match self.method {
+            Method::Simulate => Ok(cave.clone().simulate()),
+            Method::Path => Ok(cave.clone().simulate_along_path()),
+            Method::Flood => cave
+                .flood_fill()
+                .context("The flood fill only works with a floor"),
+            Method::Check => {
+                let simulated = cave.clone().simulate();
+                let along_path = cave.clone().simulate_along_path();
+
+                if along_path != simulated {
+                    bail!(
+                        "The simulation rested {} grains but following the path rested {}",
+                        simulated,
+                        along_path
+                    );
+                }
+
+                if let Some(flooded) = cave.flood_fill() {
+                    if flooded != simulated {
+                        bail!(
+                            "The simulation rested {} grains but the flood fill found {}",
+                            simulated,
+                            flooded
+                        );
+                    }
+                }
+
+                Ok(simulated)
+            }
+        }
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Parsed = Scan;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const PARAMS: &'static [(&'static str, &'static str)] = &[
+        (
+            "method",
+            "how sand is dropped: simulate, path (default), flood (part 2 only) or check",
+        ),
+        (
+            "rules",
+            "how sand falls: standard (default), diagonal-first, wind-left, wind-right \
+             or sideways steps such as 0,-1,1, each at most 1000 either way",
+        ),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let mut lines: Vec<Vec<Point>> = vec![];
+
+        for l in input.lines() {
+            let pieces: Vec<&str> = l.split(" -> ").collect();
+            let path = pieces
+                .iter()
+                .map(|piece| parse_point(input, piece))
+                .collect::<Result<Vec<Point>, _>>()?;
+
+            for (idx, pair) in path.windows(2).enumerate() {
+                if pair[0].x != pair[1].x && pair[0].y != pair[1].y {
+                    return Err(ParseError::new(
+                        input,
+                        pieces[idx + 1],
+                        "a point in line with the previous one",
+                    ));
+                }
+            }
+
+            lines.push(path);
+        }
+
+        if lines.is_empty() {
+            return Err(ParseError::new(input, input, "at least one rock path"));
+        }
+
+        Ok(Scan {
+            paths: lines,
+            method: Method::default(),
+            physics: Physics::default(),
+        })
+    }
+
+    fn configure(scan: &mut Self::Parsed, params: &Params) -> Result<()> {
+        if let Some(method) = params.get("method")? {
+            scan.method = method;
+        }
+
+        if let Some(physics) = params.get("rules")? {
+            scan.physics = physics;
+        }
+
+        Ok(())
+    }
+
+    fn part1(scan: &Self::Parsed) -> Result<Self::Answer1> {
+        scan.count_sand(false)
+    }
+
+    fn part2(scan: &Self::Parsed) -> Result<Self::Answer2> {
+        scan.count_sand(true)
+    }
+}
@@ -0,0 +1,512 @@
+use anyhow::{bail, Context, Result};
+use std::{ops::RangeInclusive, str::FromStr};
+
+use super::Solution;
+use crate::{
+    interval::IntervalSet,
+    params::Params,
+    parse::{parse_lines, parse_value, split_once, strip_prefix, ParseError},
+};
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub struct Coord {
+    pub x: isize,
+    pub y: isize,
+}
+
+#[derive(Debug)]
+pub struct Sensor {
+    pub x: isize,
+    pub y: isize,
+    pub closest_beacon: Coord,
+}
+
+impl Coord {
+    /// Parses an `x=.., y=..` pair, reporting errors by their position in `source`.
+    pub fn parse_in(source: &str, s: &str) -> Result<Self, ParseError> {
+        let (x_str, y_str) = split_once(source, s, ", ")?;
+
+        Ok(Coord {
+            x: parse_value(source, strip_prefix(source, x_str, "x=")?, "an integer")?,
+            y: parse_value(source, strip_prefix(source, y_str, "y=")?, "an integer")?,
+        })
+    }
+}
+
+impl FromStr for Sensor {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rest = strip_prefix(s, s, "Sensor at ")?;
+        let (sensor_info, beacon_info) = split_once(s, rest, ": closest beacon is at ")?;
+
+        let sensor = Coord::parse_in(s, sensor_info)?;
+
+        Ok(Sensor {
+            x: sensor.x,
+            y: sensor.y,
+            closest_beacon: Coord::parse_in(s, beacon_info)?,
+        })
+    }
+}
+
+impl Sensor {
+    pub fn manhattan_distance(&self, other: &Coord) -> isize {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// How far the sensor can see: the distance to its closest beacon.
+    pub fn radius(&self) -> isize {
+        self.manhattan_distance(&self.closest_beacon)
+    }
+
+    /// Whether `c` is close enough that a beacon there would have been found.
+    pub fn covers(&self, c: &Coord) -> bool {
+        self.manhattan_distance(c) <= self.radius()
+    }
+
+    pub fn get_range_at_row(&self, desired_row: isize) -> Option<RangeInclusive<isize>> {
+        let radius = self.radius();
+        let offset = radius - (self.y - desired_row).abs();
+
+        if offset < 0 {
+            return None;
+        }
+        Some(self.x - offset..=self.x + offset)
+    }
+}
+
+/// Returns the columns of `desired_row` covered by any sensor.
+pub fn normalize_ranges(sensors: &[Sensor], desired_row: isize) -> IntervalSet {
+    sensors
+        .iter()
+        .filter_map(|s| s.get_range_at_row(desired_row))
+        .collect()
+}
+
+/// Finds the only position within `0..=bound` on both axes that no sensor
+/// covers, by looking for a gap in each row in turn.
+pub fn find_beacon_by_scan(sensors: &[Sensor], bound: isize) -> Option<Coord> {
+    let bounds = 0..=bound;
+
+    (0..=bound).find_map(|y| {
+        let covered = normalize_ranges(sensors, y);
+        if covered.contains_range(bounds.clone()) {
+            return None;
+        }
+
+        let gap = covered.gaps(bounds.clone()).next()?;
+
+        Some(Coord { x: *gap.start(), y })
+    })
+}
+
+/// Finds the same position as [`find_beacon_by_scan`], using the fact that an
+/// isolated gap must sit just outside the range of several sensors.
+///
+/// The points one step beyond a sensor's range lie on the lines `y = x + a`
+/// and `y = -x + b` around it, so only the points where such lines cross
+/// (plus the corners of the search area) need checking. Returns `None` if
+/// none of those are uncovered, which can happen when the gap runs along an
+/// edge of the search area.
+pub fn find_beacon_by_geometry(sensors: &[Sensor], bound: isize) -> Option<Coord> {
+    let mut rising: Vec<isize> = vec![];
+    let mut falling: Vec<isize> = vec![];
+
+    for sensor in sensors {
+        let distance = sensor.radius() + 1;
+
+        rising.extend([
+            sensor.y - sensor.x - distance,
+            sensor.y - sensor.x + distance,
+        ]);
+        falling.extend([
+            sensor.y + sensor.x - distance,
+            sensor.y + sensor.x + distance,
+        ]);
+    }
+
+    let crossings = rising.iter().flat_map(|&a| {
+        falling
+            .iter()
+            .filter(move |&&b| (a + b).rem_euclid(2) == 0)
+            .map(move |&b| Coord {
+                x: (b - a) / 2,
+                y: (a + b) / 2,
+            })
+    });
+
+    let corners = [(0, 0), (0, bound), (bound, 0), (bound, bound)].map(|(x, y)| Coord { x, y });
+
+    crossings
+        .chain(corners)
+        .filter(|c| (0..=bound).contains(&c.x) && (0..=bound).contains(&c.y))
+        .find(|c| !sensors.iter().any(|s| s.covers(c)))
+}
+
+/// A rectangle of the map, inclusive on all sides.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Window {
+    pub min: Coord,
+    pub max: Coord,
+}
+
+impl FromStr for Window {
+    type Err = ParseError;
+
+    /// Parses `x0,y0,x1,y1`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let values = s
+            .split(',')
+            .map(|v| parse_value(s, v, "an integer"))
+            .collect::<Result<Vec<isize>, _>>()?;
+
+        match values[..] {
+            [x0, y0, x1, y1] if x0 <= x1 && y0 <= y1 => Ok(Window {
+                min: Coord { x: x0, y: y0 },
+                max: Coord { x: x1, y: y1 },
+            }),
+            _ => Err(ParseError::new(
+                s,
+                s,
+                "x0,y0,x1,y1 with x0 <= x1 and y0 <= y1",
+            )),
+        }
+    }
+}
+
+impl Window {
+    /// The smallest window holding every sensor and beacon.
+    pub fn around(sensors: &[Sensor]) -> Self {
+        let points: Vec<Coord> = sensors
+            .iter()
+            .flat_map(|s| [Coord { x: s.x, y: s.y }, s.closest_beacon])
+            .collect();
+
+        Window {
+            min: Coord {
+                x: points.iter().map(|c| c.x).min().unwrap_or(0),
+                y: points.iter().map(|c| c.y).min().unwrap_or(0),
+            },
+            max: Coord {
+                x: points.iter().map(|c| c.x).max().unwrap_or(0),
+                y: points.iter().map(|c| c.y).max().unwrap_or(0),
+            },
+        }
+    }
+
+    /// The output size when every `scale` x `scale` block is one character or pixel.
+    pub fn scaled_size(&self, scale: usize) -> (usize, usize) {
+        let width = self.max.x.abs_diff(self.min.x) + 1;
+        let height = self.max.y.abs_diff(self.min.y) + 1;
+
+        (width.div_ceil(scale), height.div_ceil(scale))
+    }
+
+    /// The smallest scale that fits the window in `max_size` characters or
+    /// pixels on each side.
+    pub fn scale_to_fit(&self, max_size: usize) -> usize {
+        let (width, height) = self.scaled_size(1);
+
+        width.max(height).div_ceil(max_size).max(1)
+    }
+
+    /// The top-left map position of the block drawn at (`col`, `row`).
+    fn block_origin(&self, scale: usize, col: usize, row: usize) -> Coord {
+        Coord {
+            x: self.min.x + (col * scale) as isize,
+            y: self.min.y + (row * scale) as isize,
+        }
+    }
+
+    /// The block a map position is drawn in, if it's inside the window.
+    fn block_of(&self, scale: usize, c: Coord) -> Option<(usize, usize)> {
+        if c.x < self.min.x || c.x > self.max.x || c.y < self.min.y || c.y > self.max.y {
+            return None;
+        }
+
+        Some((
+            (c.x - self.min.x) as usize / scale,
+            (c.y - self.min.y) as usize / scale,
+        ))
+    }
+}
+
+/// Draws the map in the puzzle's style: `S` for sensors, `B` for beacons, `#`
+/// for positions a sensor covers and `.` for the rest. With a `scale` above 1
+/// each character stands for a block of positions, drawn as `S` or `B` if one
+/// is inside it and otherwise by whether its top-left position is covered.
+pub fn render_ascii(sensors: &[Sensor], window: Window, scale: usize) -> String {
+    let (width, height) = window.scaled_size(scale);
+    let mut map: Vec<Vec<char>> = (0..height)
+        .map(|row| {
+            (0..width)
+                .map(|col| {
+                    let c = window.block_origin(scale, col, row);
+
+                    if sensors.iter().any(|s| s.covers(&c)) {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    let beacons = sensors.iter().map(|s| (s.closest_beacon, 'B'));
+    let sensor_positions = sensors.iter().map(|s| (Coord { x: s.x, y: s.y }, 'S'));
+
+    // Sensors are drawn last so they win over a beacon in the same block.
+    for (c, ch) in beacons.chain(sensor_positions) {
+        if let Some((col, row)) = window.block_of(scale, c) {
+            map[row][col] = ch;
+        }
+    }
+
+    map.into_iter()
+        .map(|row| row.into_iter().collect::<String>() + "\n")
+        .collect()
+}
+
+const UNCOVERED_COLOR: [u8; 3] = [0, 0, 0];
+const SENSOR_COLOR: [u8; 3] = [230, 40, 40];
+const BEACON_COLOR: [u8; 3] = [250, 200, 0];
+const GAP_COLOR: [u8; 3] = [0, 255, 80];
+
+/// Draws the map as a binary PPM image, one pixel per `scale` x `scale`
+/// block. Covered positions are grey, brighter where more sensors overlap.
+/// Sensors, beacons and `gap` are drawn as markers a few pixels wide so they
+/// stay visible however far the map is shrunk.
+pub fn render_ppm(sensors: &[Sensor], window: Window, scale: usize, gap: Option<Coord>) -> Vec<u8> {
+    let (width, height) = window.scaled_size(scale);
+    let mut pixels: Vec<[u8; 3]> = Vec::with_capacity(width * height);
+
+    for row in 0..height {
+        for col in 0..width {
+            let c = window.block_origin(scale, col, row);
+            let overlap = sensors.iter().filter(|s| s.covers(&c)).count();
+
+            pixels.push(match overlap {
+                0 => UNCOVERED_COLOR,
+                n => [(40 + 30 * n).min(220) as u8; 3],
+            });
+        }
+    }
+
+    let mut draw_marker = |c: Coord, radius: isize, color: [u8; 3]| {
+        let Some((col, row)) = window.block_of(scale, c) else {
+            return;
+        };
+
+        for dy in -radius..=radius {
+            for dx in -radius..=radius {
+                let (x, y) = (col as isize + dx, row as isize + dy);
+
+                if (0..width as isize).contains(&x) && (0..height as isize).contains(&y) {
+                    pixels[y as usize * width + x as usize] = color;
+                }
+            }
+        }
+    };
+
+    for sensor in sensors {
+        draw_marker(sensor.closest_beacon, 1, BEACON_COLOR);
+        draw_marker(
+            Coord {
+                x: sensor.x,
+                y: sensor.y,
+            },
+            1,
+            SENSOR_COLOR,
+        );
+    }
+
+    if let Some(gap) = gap {
+        draw_marker(gap, 3, GAP_COLOR);
+    }
+
+    let mut image = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+    image.extend(pixels.into_iter().flatten());
+
+    image
+}
+
+/// How part 2 looks for the distress beacon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Method {
+    /// [`find_beacon_by_geometry`], falling back to a scan if it finds nothing.
+    #[default]
+    Geometry,
+    /// [`find_beacon_by_scan`].
+    Scan,
+    /// Both, failing if they disagree.
+    Check,
+}
+
+impl FromStr for Method {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "geometry" => Ok(Method::Geometry),
+            "scan" => Ok(Method::Scan),
+            "check" => Ok(Method::Check),
+            _ => bail!("expected geometry, scan or check"),
+        }
+    }
+}
+
+/// The row checked by part 1 and the search bound for part 2 in the real
+/// puzzle, and in the example from the puzzle text.
+pub const ROW: isize = 2_000_000;
+pub const BOUND: isize = 4_000_000;
+pub const EXAMPLE_ROW: isize = 10;
+pub const EXAMPLE_BOUND: isize = 20;
+
+/// Sensors further than this from the origin mean the real puzzle's settings.
+const EXAMPLE_EXTENT: isize = 1000;
+
+pub const TUNING_MULTIPLIER: isize = 4_000_000;
+
+#[derive(Debug)]
+pub struct Survey {
+    pub sensors: Vec<Sensor>,
+    pub method: Method,
+    /// The row part 1 counts covered positions in.
+    pub row: isize,
+    /// Part 2 searches `0..=bound` on both axes.
+    pub bound: isize,
+    /// The tuning frequency is `x * tuning_multiplier + y`.
+    pub tuning_multiplier: isize,
+}
+
+impl Survey {
+    /// Uses the example's settings if every sensor is close to the origin,
+    /// and the real puzzle's otherwise.
+    pub fn new(sensors: Vec<Sensor>) -> Self {
+        let is_example = sensors
+            .iter()
+            .all(|s| s.x.abs() <= EXAMPLE_EXTENT && s.y.abs() <= EXAMPLE_EXTENT);
+
+        let (row, bound) = if is_example {
+            (EXAMPLE_ROW, EXAMPLE_BOUND)
+        } else {
+            (ROW, BOUND)
+        };
+
+        Self {
+            sensors,
+            method: Method::default(),
+            row,
+            bound,
+            tuning_multiplier: TUNING_MULTIPLIER,
+        }
+    }
+
+    /// The one position within the bounds no sensor covers, found with `method`.
+    pub fn find_beacon(&self) -> Result<Coord> {
+        let (sensors, bound) = (&self.sensors, self.bound);
+
+        match self.method {
+            Method::Geometry => find_beacon_by_geometry(sensors, bound)
+                .or_else(|| find_beacon_by_scan(sensors, bound)),
+            Method::Scan => find_beacon_by_scan(sensors, bound),
+            Method::Check => {
+                let geometry = find_beacon_by_geometry(sensors, bound);
+                let scan = find_beacon_by_scan(sensors, bound);
+
+                if geometry != scan {
+                    bail!(
+                        "The geometry solver found {:?} but the scan found {:?}",
+                        geometry,
+                        scan
+                    );
+                }
+
+                scan
+            }
+        }
+        .context("Every position within the bounds is covered by a sensor")
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Parsed = Survey;
+    type Answer1 = usize;
+    type Answer2 = isize;
+
+    const PARAMS: &'static [(&'static str, &'static str)] = &[
+        (
+            "row",
+            "the row part 1 counts (default 10 for the example, else 2000000)",
+        ),
+        (
+            "bound",
+            "part 2 searches 0..=bound (default 20 for the example, else 4000000)",
+        ),
+        (
+            "multiplier",
+            "the tuning frequency is x * multiplier + y (default 4000000)",
+        ),
+        (
+            "method",
+            "how part 2 finds the beacon: geometry (default), scan or check",
+        ),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Ok(Survey::new(parse_lines(input)?))
+    }
+
+    fn configure(survey: &mut Self::Parsed, params: &Params) -> Result<()> {
+        if let Some(row) = params.get("row")? {
+            survey.row = row;
+        }
+
+        if let Some(bound) = params.get("bound")? {
+            survey.bound = bound;
+        }
+
+        if let Some(multiplier) = params.get("multiplier")? {
+            survey.tuning_multiplier = multiplier;
+        }
+
+        if let Some(method) = params.get("method")? {
+            survey.method = method;
+        }
+
+        Ok(())
+    }
+
+    fn part1(survey: &Self::Parsed) -> Result<Self::Answer1> {
+        let sensors = &survey.sensors;
+
+        let beacons: IntervalSet = sensors
+            .iter()
+            .map(|s| s.closest_beacon)
+            .filter(|b| b.y == survey.row)
+            .map(|b| b.x..=b.x)
+            .collect();
+
+        Ok(normalize_ranges(sensors, survey.row)
+            .difference(&beacons)
+            .len())
+    }
+
+    fn part2(survey: &Self::Parsed) -> Result<Self::Answer2> {
+        let beacon = survey.find_beacon()?;
+        beacon
+            .x
+            .checked_mul(survey.tuning_multiplier)
+            .and_then(|f| f.checked_add(beacon.y))
+            .with_context(|| {
+                format!(
+                    "The tuning frequency of the beacon at {}, {} overflows",
+                    beacon.x, beacon.y
+                )
+            })
+    }
+}
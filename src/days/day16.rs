@@ -0,0 +1,1112 @@
+use anyhow::{bail, Context, Result};
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BTreeSet, HashMap, HashSet},
+    str::FromStr,
+};
+
+use super::Solution;
+use crate::{
+    params::Params,
+    parse::{parse_value, split_once, strip_prefix, ParseError},
+    search::{self, Neighbors},
+};
+
+/// A valve, as its index in the [`Network`] it came from. Keys are handed out
+/// in order of name, so sorting keys sorts their names too.
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, PartialOrd, Ord)]
+pub struct ValveKey(pub u16);
+
+/// Where both you and the elephant start.
+pub const START: &str = "AA";
+
+/// Minutes before the volcano erupts, how many of them go on teaching the
+/// elephant, and how long a valve takes to open.
+pub const TIME_ALONE: u64 = 30;
+pub const TEACHING_TIME: u64 = 4;
+pub const OPEN_TIME: u64 = 1;
+
+/// The most minutes the `time` and `open_time` parameters allow. The
+/// searches grow quickly with the time left, and at twice the puzzle's time
+/// they already take seconds.
+pub const MAX_TIME: u64 = 60;
+
+#[derive(Debug)]
+pub struct Valve {
+    pub key: ValveKey,
+    pub tunnels: Vec<ValveKey>,
+    pub rate: u64,
+}
+
+/// The name of every valve and the key it was given.
+#[derive(Debug, Default)]
+pub struct ValveNames {
+    names: Vec<String>,
+    keys: HashMap<String, ValveKey>,
+}
+
+#[derive(Debug)]
+pub struct Network {
+    pub names: ValveNames,
+    /// Every valve, indexed by its key.
+    pub valves: Vec<Valve>,
+}
+
+/// The network reduced to the start valve and the valves worth opening, with
+/// the minutes it takes to walk between each pair of them. The start is
+/// always index 0.
+#[derive(Debug)]
+pub struct CompressedNetwork {
+    pub keys: Vec<ValveKey>,
+    pub rates: Vec<u64>,
+    /// Minutes it takes to open a valve once you're there.
+    pub open_time: u64,
+    distances: Vec<Option<u64>>,
+    /// Valve indexes from the highest flow rate to the lowest.
+    by_rate: Vec<usize>,
+}
+
+/// What one of you does in a minute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    MoveTo(ValveKey),
+    Open(ValveKey),
+}
+
+/// Everything one of you does, a minute at a time from minute 1. Once the
+/// steps run out, the rest of the time is spent waiting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    pub start: ValveKey,
+    pub steps: Vec<Step>,
+}
+
+/// How the valves are searched for the routes that release the most pressure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Method {
+    /// Every order of opening the valves.
+    Exhaustive,
+    /// A depth-first search that skips routes which can't beat the best one
+    /// found so far.
+    #[default]
+    Bound,
+    /// A DP over (position, time, opened valves) for the best pressure of
+    /// every set of valves.
+    Dp,
+}
+
+/// A set of valves in a `CompressedNetwork`, one bit per index.
+#[derive(Debug, Clone, Copy, Default, Hash, Eq, PartialEq)]
+pub struct ValveSet(pub u64);
+
+#[derive(Debug)]
+pub struct Move {
+    pub target: usize,
+    pub reward: u64,
+    /// Minutes to walk to the valve and open it.
+    pub minutes: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct State<'a> {
+    pub max_time: u64,
+    pub network: &'a CompressedNetwork,
+    pub current_position: usize,
+    pub current_time: u64,
+    pub opened_valves: ValveSet,
+    /// The valves opened so far, in order.
+    pub route: Vec<ValveKey>,
+    pub pressure: u64,
+}
+
+impl ValveNames {
+    /// Gives each of `names` a key in sorted order, failing with the first
+    /// name, in the order given, that's already been taken.
+    fn intern<'a>(names: impl IntoIterator<Item = &'a str>) -> Result<Self, &'a str> {
+        let mut sorted: Vec<&str> = names.into_iter().collect();
+
+        let mut seen = HashSet::new();
+        if let Some(&taken) = sorted.iter().find(|&&name| !seen.insert(name)) {
+            return Err(taken);
+        }
+
+        sorted.sort_unstable();
+
+        let names: Vec<String> = sorted.into_iter().map(str::to_string).collect();
+        let keys = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (name.clone(), ValveKey(i as u16)))
+            .collect();
+
+        Ok(Self { names, keys })
+    }
+
+    pub fn key(&self, name: &str) -> Option<ValveKey> {
+        self.keys.get(name).copied()
+    }
+
+    pub fn name(&self, key: ValveKey) -> &str {
+        &self.names[key.0 as usize]
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+/// One line of input, before its valve names have been given keys.
+struct ValveLine<'a> {
+    name: &'a str,
+    rate: u64,
+    tunnels: Vec<&'a str>,
+}
+
+impl<'a> ValveLine<'a> {
+    fn parse(s: &'a str) -> Result<Self, ParseError> {
+        let (valve_info, tunnel_info) = split_once(s, s, "; ")?;
+
+        let valve_info = strip_prefix(s, valve_info, "Valve ")?;
+        let (name, rate) = split_once(s, valve_info, " has flow rate=")?;
+
+        let tunnel_list = ["tunnels lead to valves ", "tunnel leads to valve "]
+            .iter()
+            .find_map(|prefix| tunnel_info.strip_prefix(prefix))
+            .ok_or_else(|| ParseError::new(s, tunnel_info, "a list of tunnels"))?;
+
+        Ok(Self {
+            name: valve_name(s, name)?,
+            rate: parse_value(s, rate, "a flow rate")?,
+            tunnels: tunnel_list
+                .split(", ")
+                .map(|t| valve_name(s, t))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/// Checks that `found` (a slice of `source`) is a name, which can be anything
+/// but whitespace, `,` and `;`.
+fn valve_name<'a>(source: &str, found: &'a str) -> Result<&'a str, ParseError> {
+    if found.is_empty()
+        || found
+            .chars()
+            .any(|c| c.is_whitespace() || c == ',' || c == ';')
+    {
+        return Err(ParseError::new(source, found, "a valve name"));
+    }
+
+    Ok(found)
+}
+
+impl FromStr for Network {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<(usize, &str, ValveLine)> = input
+            .lines()
+            .enumerate()
+            .map(|(i, l)| Ok((i, l, ValveLine::parse(l).map_err(|e| e.offset(i))?)))
+            .collect::<Result<_, ParseError>>()?;
+
+        if lines.len() > u16::MAX as usize + 1 {
+            let (i, line, _) = lines.last().unwrap();
+            return Err(ParseError::new(line, line, "at most 65536 valves").offset(*i));
+        }
+
+        let names =
+            ValveNames::intern(lines.iter().map(|(_, _, valve)| valve.name)).map_err(|taken| {
+                let (i, line, valve) = lines
+                    .iter()
+                    .filter(|(_, _, valve)| valve.name == taken)
+                    .nth(1)
+                    .unwrap();
+
+                ParseError::new(line, valve.name, "a valve name that isn't already taken")
+                    .offset(*i)
+            })?;
+
+        let mut valves = vec![];
+        for (i, line, valve) in &lines {
+            let tunnels = valve
+                .tunnels
+                .iter()
+                .map(|&t| {
+                    names.key(t).ok_or_else(|| {
+                        ParseError::new(line, t, "the name of a valve in the network").offset(*i)
+                    })
+                })
+                .collect::<Result<_, _>>()?;
+
+            valves.push(Valve {
+                key: names.key(valve.name).unwrap(),
+                rate: valve.rate,
+                tunnels,
+            });
+        }
+        valves.sort_unstable_by_key(|valve| valve.key);
+
+        Ok(Network { names, valves })
+    }
+}
+
+impl<'a> Neighbors<ValveKey> for &'a Network {
+    type Iter = std::iter::Map<std::slice::Iter<'a, ValveKey>, fn(&ValveKey) -> (ValveKey, usize)>;
+
+    fn neighbors(&self, key: &ValveKey) -> Self::Iter {
+        self.valve(*key).tunnels.iter().map(|&t| (t, 1))
+    }
+}
+
+impl Network {
+    pub fn valve(&self, key: ValveKey) -> &Valve {
+        &self.valves[key.0 as usize]
+    }
+
+    pub fn name(&self, key: ValveKey) -> &str {
+        self.names.name(key)
+    }
+
+    /// The shortest path from `start` to every reachable valve, not counting
+    /// `start` itself.
+    pub fn get_shortest_paths(&self, start: ValveKey) -> HashMap<ValveKey, Vec<ValveKey>> {
+        let paths = search::bfs_all(&self, start);
+
+        let mut reached: Vec<(ValveKey, usize)> =
+            paths.reached().map(|(&key, cost)| (key, cost)).collect();
+        reached.sort_unstable_by_key(|&(_, cost)| cost);
+
+        // Every parent is closer than its children, so its path is already known.
+        let mut connections: HashMap<ValveKey, Vec<ValveKey>> =
+            HashMap::with_capacity(reached.len());
+        for (key, _) in reached {
+            let path = match paths.parent(&key) {
+                Some(parent) => {
+                    let mut path = connections[parent].clone();
+                    path.push(key);
+                    path
+                }
+                None => vec![],
+            };
+
+            connections.insert(key, path);
+        }
+
+        connections
+    }
+
+    /// Follows `state`'s route from the start, walking the shortest path to
+    /// each valve in turn and opening it.
+    pub fn plan(&self, state: &State) -> Plan {
+        let start = state.network.keys[0];
+        let mut steps = vec![];
+        let mut position = start;
+
+        for &valve in &state.route {
+            let path = &self.get_shortest_paths(position)[&valve];
+
+            steps.extend(path.iter().map(|&key| Step::MoveTo(key)));
+            steps.extend((0..state.network.open_time).map(|_| Step::Open(valve)));
+            position = valve;
+        }
+
+        Plan { start, steps }
+    }
+
+    /// The network as a Graphviz graph, with each valve labelled with its
+    /// flow rate and the zero-rate corridors greyed out. Each of `plans` is
+    /// drawn over the tunnels in its own colour, numbering the moves in order.
+    pub fn to_dot(&self, plans: &[(&str, &Plan)]) -> String {
+        const COLORS: [&str; 4] = ["red", "blue", "darkgreen", "orange"];
+
+        let mut lines = vec!["graph valves {".to_string()];
+
+        for valve in &self.valves {
+            let name = self.name(valve.key);
+            let style = if valve.rate == 0 {
+                ", style=filled, fillcolor=lightgrey, fontcolor=gray40"
+            } else {
+                ""
+            };
+
+            lines.push(format!(
+                "    \"{}\" [label=\"{}\\nrate {}\"{}];",
+                name, name, valve.rate, style
+            ));
+        }
+
+        // Every tunnel is listed from both ends, so draw each once.
+        let tunnels: BTreeSet<(ValveKey, ValveKey)> = self
+            .valves
+            .iter()
+            .flat_map(|valve| {
+                let key = valve.key;
+                valve
+                    .tunnels
+                    .iter()
+                    .map(move |&other| (key.min(other), key.max(other)))
+            })
+            .collect();
+
+        for (a, b) in tunnels {
+            lines.push(format!("    \"{}\" -- \"{}\";", self.name(a), self.name(b)));
+        }
+
+        for (&(who, plan), color) in plans.iter().zip(COLORS.iter().cycle()) {
+            let moves = plan.steps.iter().filter_map(|step| match step {
+                Step::MoveTo(key) => Some(*key),
+                Step::Open(_) => None,
+            });
+
+            let mut position = plan.start;
+            for (order, key) in moves.enumerate() {
+                lines.push(format!(
+                    "    \"{}\" -- \"{}\" [label=\"{}\", tooltip=\"{}\", color={}, fontcolor={}, penwidth=2];",
+                    self.name(position),
+                    self.name(key),
+                    order + 1,
+                    who,
+                    color,
+                    color
+                ));
+                position = key;
+            }
+        }
+
+        lines.push("}".to_string());
+        lines.join("\n") + "\n"
+    }
+
+    /// Keeps only `start` and the valves with a positive flow rate, with the
+    /// shortest distance between each pair found by a BFS from each of them.
+    pub fn compress(&self, start: &str, open_time: u64) -> Result<CompressedNetwork> {
+        let start = self
+            .names
+            .key(start)
+            .with_context(|| format!("There is no valve {}", start))?;
+
+        let mut keys = vec![start];
+        let mut others: Vec<ValveKey> = self
+            .valves
+            .iter()
+            .filter(|valve| valve.rate > 0 && valve.key != start)
+            .map(|valve| valve.key)
+            .collect();
+        others.sort_unstable();
+        keys.extend(others);
+
+        if keys.len() > 64 {
+            bail!(
+                "Too many valves with a flow rate ({}), at most 63 are supported",
+                keys.len() - 1
+            );
+        }
+
+        let distances = keys
+            .iter()
+            .flat_map(|&from| {
+                let paths = search::bfs_all(&self, from);
+                keys.iter()
+                    .map(move |to| paths.cost(to).map(|cost| cost as u64))
+            })
+            .collect();
+
+        let rates: Vec<u64> = keys.iter().map(|&key| self.valve(key).rate).collect();
+        let mut by_rate: Vec<usize> = (0..keys.len()).collect();
+        by_rate.sort_by_key(|&valve| Reverse(rates[valve]));
+
+        Ok(CompressedNetwork {
+            keys,
+            rates,
+            open_time,
+            distances,
+            by_rate,
+        })
+    }
+}
+
+impl CompressedNetwork {
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Minutes to walk from valve `from` to valve `to`, or `None` if there's
+    /// no way through.
+    pub fn distance(&self, from: usize, to: usize) -> Option<u64> {
+        self.distances[from * self.len() + to]
+    }
+
+    /// The pressure `valves` release together each minute.
+    pub fn flow(&self, valves: ValveSet) -> u64 {
+        (0..self.len())
+            .filter(|&valve| valves.contains(valve))
+            .map(|valve| self.rates[valve])
+            .sum()
+    }
+
+    /// Every valve worth opening from `position` with `turns_left` minutes
+    /// to go, leaving out the ones in `opened` and any that would be opened
+    /// too late to release anything.
+    pub fn moves_from(
+        &self,
+        position: usize,
+        opened: ValveSet,
+        turns_left: u64,
+    ) -> impl Iterator<Item = Move> + '_ {
+        (0..self.len()).filter_map(move |target| {
+            let rate = self.rates[target];
+
+            if rate == 0 || opened.contains(target) {
+                return None;
+            }
+
+            let minutes = self.distance(position, target)? + self.open_time;
+            let time_spent = turns_left.checked_sub(minutes).filter(|&time| time > 0)?;
+
+            Some(Move {
+                target,
+                reward: time_spent * rate,
+                minutes,
+            })
+        })
+    }
+}
+
+impl ValveSet {
+    pub fn contains(self, valve: usize) -> bool {
+        self.0 & (1 << valve) != 0
+    }
+
+    pub fn with(self, valve: usize) -> Self {
+        Self(self.0 | 1 << valve)
+    }
+
+    /// Every valve not in this set.
+    pub fn complement(self) -> Self {
+        Self(!self.0)
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn is_disjoint(self, other: Self) -> bool {
+        self.0 & other.0 == 0
+    }
+}
+
+impl<'a> State<'a> {
+    pub fn new(network: &'a CompressedNetwork, max_time: u64) -> Self {
+        Self {
+            max_time,
+            network,
+            current_position: 0,
+            current_time: 0,
+            opened_valves: ValveSet::default(),
+            route: vec![],
+            pressure: 0,
+        }
+    }
+
+    /// Treats `valves` as already opened, so nothing searched from here
+    /// opens them.
+    pub fn excluding(mut self, valves: ValveSet) -> Self {
+        self.opened_valves = ValveSet(self.opened_valves.0 | valves.0);
+        self
+    }
+
+    pub fn turns_left(&self) -> u64 {
+        self.max_time - self.current_time
+    }
+
+    pub fn apply_move(&self, m: &Move) -> Self {
+        let mut next_state = self.clone();
+        next_state.take_move(m);
+
+        next_state
+    }
+
+    /// Makes `m` in place. [`undo_move`](Self::undo_move) puts the state back
+    /// the way it was.
+    fn take_move(&mut self, m: &Move) {
+        self.current_position = m.target;
+        self.opened_valves = self.opened_valves.with(m.target);
+        self.route.push(self.network.keys[m.target]);
+        self.current_time += m.minutes;
+        self.pressure += m.reward;
+    }
+
+    fn undo_move(&mut self, m: &Move, position: usize, opened: ValveSet) {
+        self.current_position = position;
+        self.opened_valves = opened;
+        self.route.pop();
+        self.current_time -= m.minutes;
+        self.pressure -= m.reward;
+    }
+
+    pub fn get_possible_moves(&self) -> Vec<Move> {
+        self.network
+            .moves_from(self.current_position, self.opened_valves, self.turns_left())
+            .collect()
+    }
+
+    /// An optimistic bound on the pressure the closed valves could still
+    /// release. Reaching and opening a valve takes at least a minute more
+    /// than opening it, except for the valve we're standing at, so this
+    /// pretends the biggest valves are opened first with one minute of
+    /// walking between each.
+    pub fn upper_bound(&self) -> u64 {
+        self.bound(self.opened_valves, self.turns_left())
+    }
+
+    /// The [`upper_bound`](Self::upper_bound) of a state with `opened` valves
+    /// and `turns_left` minutes, so a move can be judged before it's made.
+    fn bound(&self, opened: ValveSet, turns_left: u64) -> u64 {
+        let closed = self
+            .network
+            .by_rate
+            .iter()
+            .filter(|&&valve| !opened.contains(valve))
+            .map(|&valve| self.network.rates[valve]);
+
+        let step = self.network.open_time + 1;
+        let minutes_open =
+            std::iter::successors(turns_left.checked_sub(self.network.open_time), |minutes| {
+                minutes.checked_sub(step)
+            });
+
+        closed
+            .zip(minutes_open)
+            .map(|(rate, minutes)| rate * minutes)
+            .sum()
+    }
+
+    pub fn get_best_state_dfs(&self) -> Searched<Self> {
+        let mut nodes = 0;
+        let best = self.exhaustive_search(&mut nodes);
+
+        Searched { best, nodes }
+    }
+
+    fn exhaustive_search(&self, nodes: &mut usize) -> Self {
+        *nodes += 1;
+
+        let mut best_state = self.clone();
+        let mut max_pressure: u64 = 0;
+
+        let mut all_moves = self.get_possible_moves();
+        all_moves.sort_unstable_by_key(|m| Reverse(m.reward));
+
+        for m in all_moves {
+            let next_state = self.apply_move(&m).exhaustive_search(nodes);
+
+            if next_state.pressure > max_pressure {
+                max_pressure = next_state.pressure;
+                best_state = next_state;
+            }
+        }
+
+        best_state
+    }
+
+    /// The best state reachable from this one, skipping any branch whose
+    /// [`upper_bound`](Self::upper_bound) can't beat the best found so far.
+    pub fn get_best_state_bounded(&self) -> Searched<Self> {
+        let mut nodes = 0;
+        let best = self
+            .better_than(0, &mut nodes)
+            .unwrap_or_else(|| self.clone());
+
+        Searched { best, nodes }
+    }
+
+    /// The best state reachable from this one that releases more than
+    /// `floor` pressure, if there is one.
+    fn better_than(&self, floor: u64, nodes: &mut usize) -> Option<Self> {
+        let mut best = None;
+        self.clone()
+            .branch_and_bound(&mut best, &mut { floor }, nodes);
+
+        best
+    }
+
+    /// Searches by making and undoing moves on this one state, so the route
+    /// is only copied when a new best is found.
+    fn branch_and_bound(&mut self, best: &mut Option<Self>, floor: &mut u64, nodes: &mut usize) {
+        *nodes += 1;
+
+        if self.pressure > *floor {
+            *floor = self.pressure;
+            *best = Some(self.clone());
+        }
+
+        let mut all_moves = self.get_possible_moves();
+        all_moves.sort_unstable_by_key(|m| Reverse(m.reward));
+
+        let (position, opened) = (self.current_position, self.opened_valves);
+
+        for m in all_moves {
+            let bound = self.bound(opened.with(m.target), self.turns_left() - m.minutes);
+
+            if self.pressure + m.reward + bound > *floor {
+                self.take_move(&m);
+                self.branch_and_bound(best, floor, nodes);
+                self.undo_move(&m, position, opened);
+            }
+        }
+    }
+
+    /// Walks every route from this state, keeping the best state for each set
+    /// of opened valves. Stopping early is allowed, so every set along the
+    /// way is included, starting with this state's own.
+    pub fn get_best_state_per_valve_set(&self) -> Searched<HashMap<ValveSet, Self>> {
+        let mut best = HashMap::new();
+        let mut nodes = 0;
+        self.collect_best_states(&mut best, &mut nodes);
+
+        Searched { best, nodes }
+    }
+
+    fn collect_best_states(&self, best: &mut HashMap<ValveSet, Self>, nodes: &mut usize) {
+        *nodes += 1;
+
+        match best.entry(self.opened_valves) {
+            Entry::Occupied(e) if e.get().pressure >= self.pressure => {}
+            Entry::Occupied(mut e) => {
+                e.insert(self.clone());
+            }
+            Entry::Vacant(e) => {
+                e.insert(self.clone());
+            }
+        }
+
+        for m in self.get_possible_moves() {
+            self.apply_move(&m).collect_best_states(best, nodes);
+        }
+    }
+}
+
+/// The result of a search, with how many states it visited along the way.
+#[derive(Debug, Clone)]
+pub struct Searched<T> {
+    pub best: T,
+    pub nodes: usize,
+}
+
+/// The best pressure for every set of valves that can be opened within
+/// `max_time`. States are keyed by (position, time, opened valves) and taken
+/// in order of time, so each is expanded once however many routes reach it.
+pub fn best_pressure_per_valve_set(
+    network: &CompressedNetwork,
+    max_time: u64,
+) -> Searched<HashMap<ValveSet, u64>> {
+    let mut by_time: Vec<HashMap<(usize, ValveSet), u64>> =
+        vec![HashMap::new(); max_time as usize + 1];
+    by_time[0].insert((0, ValveSet::default()), 0);
+
+    let mut best: HashMap<ValveSet, u64> = HashMap::new();
+    let mut nodes = 0;
+
+    for time in 0..=max_time {
+        for ((position, opened), pressure) in std::mem::take(&mut by_time[time as usize]) {
+            nodes += 1;
+
+            let best_for_set = best.entry(opened).or_default();
+            *best_for_set = (*best_for_set).max(pressure);
+
+            for m in network.moves_from(position, opened, max_time - time) {
+                let arrival = time + m.minutes;
+                let next = by_time[arrival as usize]
+                    .entry((m.target, opened.with(m.target)))
+                    .or_default();
+                *next = (*next).max(pressure + m.reward);
+            }
+        }
+    }
+
+    Searched { best, nodes }
+}
+
+/// The best pressure for every set of valves under every time budget from 0
+/// to `max_time`, in one pass. Rather than the pressure a state will release
+/// by the deadline, which depends on the budget, each state keeps what it has
+/// released so far. By any later budget it then releases that plus its flow
+/// for every minute in between.
+pub fn best_pressure_per_budget(
+    network: &CompressedNetwork,
+    max_time: u64,
+) -> Searched<Vec<HashMap<ValveSet, u64>>> {
+    let mut by_time: Vec<HashMap<(usize, ValveSet), u64>> =
+        vec![HashMap::new(); max_time as usize + 1];
+    by_time[0].insert((0, ValveSet::default()), 0);
+
+    let mut best: Vec<HashMap<ValveSet, u64>> = vec![HashMap::new(); max_time as usize + 1];
+    let mut nodes = 0;
+
+    for time in 0..=max_time {
+        for ((position, opened), released) in std::mem::take(&mut by_time[time as usize]) {
+            nodes += 1;
+
+            let flow = network.flow(opened);
+
+            for budget in time..=max_time {
+                let best_for_set = best[budget as usize].entry(opened).or_default();
+                *best_for_set = (*best_for_set).max(released + flow * (budget - time));
+            }
+
+            for m in network.moves_from(position, opened, max_time - time) {
+                let arrival = time + m.minutes;
+                let next = by_time[arrival as usize]
+                    .entry((m.target, opened.with(m.target)))
+                    .or_default();
+                *next = (*next).max(released + flow * m.minutes);
+            }
+        }
+    }
+
+    Searched { best, nodes }
+}
+
+/// The pair of disjoint valve sets that release the most pressure together.
+/// The empty set has to be one of `pressures`, so there's always an answer.
+pub fn best_disjoint_pair(pressures: &HashMap<ValveSet, u64>) -> (ValveSet, ValveSet) {
+    let mut sets: Vec<(ValveSet, u64)> = pressures.iter().map(|(&s, &p)| (s, p)).collect();
+    sets.sort_unstable_by_key(|&(_, pressure)| Reverse(pressure));
+
+    // Leaving the elephant with nothing to open is always allowed.
+    let mut best = (sets[0].0, ValveSet::default());
+    let mut best_pressure = sets[0].1;
+
+    for (i, &(a, a_pressure)) in sets.iter().enumerate() {
+        if a_pressure * 2 <= best_pressure {
+            break;
+        }
+
+        for &(b, b_pressure) in &sets[i + 1..] {
+            if a_pressure + b_pressure <= best_pressure {
+                break;
+            }
+
+            if a.is_disjoint(b) {
+                best = (a, b);
+                best_pressure = a_pressure + b_pressure;
+            }
+        }
+    }
+
+    best
+}
+
+/// Finds the route that opens exactly the valves in `valves` for the most
+/// pressure, for turning the sets the DP finds back into routes.
+fn route_for(network: &CompressedNetwork, max_time: u64, valves: ValveSet) -> State<'_> {
+    State::new(network, max_time)
+        .excluding(valves.complement())
+        .get_best_state_bounded()
+        .best
+}
+
+/// The best route on your own within `max_time`.
+pub fn best_alone(
+    network: &CompressedNetwork,
+    max_time: u64,
+    method: Method,
+) -> Searched<State<'_>> {
+    let start = State::new(network, max_time);
+
+    match method {
+        Method::Exhaustive => start.get_best_state_dfs(),
+        Method::Bound => start.get_best_state_bounded(),
+        Method::Dp => {
+            let pressures = best_pressure_per_valve_set(network, max_time);
+            let (&valves, _) = pressures
+                .best
+                .iter()
+                .max_by_key(|&(_, &pressure)| pressure)
+                .unwrap();
+
+            Searched {
+                best: route_for(network, max_time, valves),
+                nodes: pressures.nodes,
+            }
+        }
+    }
+}
+
+/// The best pair of routes for you and the elephant within `max_time`, which
+/// open disjoint sets of valves. As in the puzzle's example, the elephant
+/// takes the route that releases more.
+pub fn best_with_elephant(
+    network: &CompressedNetwork,
+    max_time: u64,
+    method: Method,
+) -> Searched<(State<'_>, State<'_>)> {
+    let start = State::new(network, max_time);
+
+    let mut searched = match method {
+        Method::Exhaustive => {
+            let states = start.get_best_state_per_valve_set();
+            let pressures = states
+                .best
+                .iter()
+                .map(|(&valves, state)| (valves, state.pressure))
+                .collect();
+            let (you, elephant) = best_disjoint_pair(&pressures);
+
+            Searched {
+                best: (states.best[&you].clone(), states.best[&elephant].clone()),
+                nodes: states.nodes,
+            }
+        }
+        Method::Bound => {
+            // Every set you could open, each followed by the elephant's best
+            // route through the rest, if it can beat the best pair so far.
+            let states = start.get_best_state_per_valve_set();
+            let mut yours: Vec<State> = states.best.into_values().collect();
+            yours.sort_unstable_by_key(|s| Reverse(s.pressure));
+
+            let mut nodes = states.nodes;
+            let mut best = (yours[0].clone(), start.clone());
+            let mut best_pressure = yours[0].pressure;
+
+            for you in yours {
+                // One of the pair releases at least half, so let that be you.
+                if you.pressure * 2 <= best_pressure {
+                    break;
+                }
+
+                let elephant = start.clone().excluding(you.opened_valves);
+
+                if you.pressure + elephant.upper_bound() <= best_pressure {
+                    continue;
+                }
+
+                if let Some(elephant) =
+                    elephant.better_than(best_pressure - you.pressure, &mut nodes)
+                {
+                    best_pressure = you.pressure + elephant.pressure;
+                    best = (you, elephant);
+                }
+            }
+
+            Searched { best, nodes }
+        }
+        Method::Dp => {
+            let pressures = best_pressure_per_valve_set(network, max_time);
+            let (you, elephant) = best_disjoint_pair(&pressures.best);
+
+            Searched {
+                best: (
+                    route_for(network, max_time, you),
+                    route_for(network, max_time, elephant),
+                ),
+                nodes: pressures.nodes,
+            }
+        }
+    };
+
+    let (you, elephant) = &mut searched.best;
+    if you.pressure > elephant.pressure {
+        std::mem::swap(you, elephant);
+    }
+
+    searched
+}
+
+impl FromStr for Method {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "exhaustive" => Ok(Method::Exhaustive),
+            "bound" => Ok(Method::Bound),
+            "dp" => Ok(Method::Dp),
+            _ => bail!("expected exhaustive, bound or dp"),
+        }
+    }
+}
+
+/// Describes `plans` minute by minute in the style of the puzzle's example,
+/// as the open valves and the pressure they release, then what each of you
+/// does. Each plan comes with who follows it: "You" or "The elephant".
+pub fn narrate(network: &Network, max_time: u64, plans: &[(&str, &Plan)]) -> String {
+    let mut open: Vec<ValveKey> = vec![];
+    let mut minutes = vec![];
+
+    for minute in 1..=max_time {
+        let mut lines = vec![format!("== Minute {} ==", minute)];
+
+        let released: u64 = open.iter().map(|&key| network.valve(key).rate).sum();
+        let names: Vec<&str> = open.iter().map(|&key| network.name(key)).collect();
+        lines.push(match names.as_slice() {
+            [] => "No valves are open.".to_string(),
+            [valve] => format!("Valve {} is open, releasing {} pressure.", valve, released),
+            valves => format!(
+                "Valves {} are open, releasing {} pressure.",
+                list(valves),
+                released
+            ),
+        });
+
+        for &(who, plan) in plans {
+            // "You move", but "The elephant moves".
+            let s = if who == "You" { "" } else { "s" };
+
+            let step = minute as usize - 1;
+
+            match plan.steps.get(step) {
+                Some(&Step::MoveTo(valve)) => lines.push(format!(
+                    "{} move{} to valve {}.",
+                    who,
+                    s,
+                    network.name(valve)
+                )),
+                // A valve that takes more than a minute only opens on the last one.
+                Some(&Step::Open(valve))
+                    if plan.steps.get(step + 1) == Some(&Step::Open(valve)) =>
+                {
+                    lines.push(format!(
+                        "{} work{} on valve {}.",
+                        who,
+                        s,
+                        network.name(valve)
+                    ))
+                }
+                Some(&Step::Open(valve)) => {
+                    lines.push(format!("{} open{} valve {}.", who, s, network.name(valve)));
+                    open.push(valve);
+                }
+                None => {}
+            }
+        }
+
+        open.sort_unstable();
+        minutes.push(lines.join("\n"));
+    }
+
+    minutes.join("\n\n") + "\n"
+}
+
+/// Joins valve names as "AA and BB" or "AA, BB, and CC".
+fn list(names: &[&str]) -> String {
+    match names {
+        [first, second] => format!("{} and {}", first, second),
+        [rest @ .., last] => format!("{}, and {}", rest.join(", "), last),
+        [] => String::new(),
+    }
+}
+
+/// The tunnel network and how to search it.
+#[derive(Debug)]
+pub struct Volcano {
+    pub network: Network,
+    pub method: Method,
+    /// The valve you and the elephant start at.
+    pub start: String,
+    /// Minutes before the volcano erupts.
+    pub time: u64,
+    /// Minutes it takes to open a valve.
+    pub open_time: u64,
+}
+
+impl Volcano {
+    pub fn compress(&self) -> Result<CompressedNetwork> {
+        self.network.compress(&self.start, self.open_time)
+    }
+
+    /// The time left once the elephant has been taught.
+    pub fn time_with_elephant(&self) -> u64 {
+        self.time.saturating_sub(TEACHING_TIME)
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Parsed = Volcano;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    const PARAMS: &'static [(&'static str, &'static str)] = &[
+        (
+            "method",
+            "how the valves are searched: exhaustive, bound (default) or dp",
+        ),
+        ("start", "the valve to start at (default AA)"),
+        (
+            "time",
+            "minutes before the eruption, up to 60 (default 30, 4 fewer with the elephant)",
+        ),
+        (
+            "open_time",
+            "minutes it takes to open a valve, up to 60 (default 1)",
+        ),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Ok(Volcano {
+            network: input.parse()?,
+            method: Method::default(),
+            start: START.to_string(),
+            time: TIME_ALONE,
+            open_time: OPEN_TIME,
+        })
+    }
+
+    fn configure(volcano: &mut Self::Parsed, params: &Params) -> Result<()> {
+        if let Some(method) = params.get("method")? {
+            volcano.method = method;
+        }
+
+        if let Some(start) = params.get("start")? {
+            volcano.start = start;
+        }
+
+        if let Some(time) = params.get("time")? {
+            if time > MAX_TIME {
+                bail!("The volcano erupts in at most {} minutes", MAX_TIME);
+            }
+
+            volcano.time = time;
+        }
+
+        if let Some(open_time) = params.get("open_time")? {
+            if open_time == 0 {
+                bail!("Opening a valve has to take at least a minute");
+            }
+
+            if open_time > MAX_TIME {
+                bail!("Opening a valve can take at most {} minutes", MAX_TIME);
+            }
+
+            volcano.open_time = open_time;
+        }
+
+        Ok(())
+    }
+
+    fn part1(volcano: &Self::Parsed) -> Result<Self::Answer1> {
+        let network = volcano.compress()?;
+
+        let searched = best_alone(&network, volcano.time, volcano.method);
+
+        Ok(searched.best.pressure)
+    }
+
+    fn part2(volcano: &Self::Parsed) -> Result<Self::Answer2> {
+        let network = volcano.compress()?;
+
+        let (you, elephant) =
+            best_with_elephant(&network, volcano.time_with_elephant(), volcano.method).best;
+
+        Ok(you.pressure + elephant.pressure)
+    }
+}
@@ -0,0 +1,134 @@
+use anyhow::{bail, Context, Result};
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use crate::{params::Params, parse::ParseError};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+
+/// A single day's puzzle: the input is parsed once and shared by both parts.
+pub trait Solution {
+    type Parsed;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    /// The `--param` settings this day accepts, with a description of each.
+    const PARAMS: &'static [(&'static str, &'static str)] = &[];
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError>;
+
+    /// Applies `--param` settings to the parsed input. Only days with
+    /// [`Solution::PARAMS`] need to override this.
+    fn configure(_input: &mut Self::Parsed, _params: &Params) -> Result<()> {
+        Ok(())
+    }
+
+    fn part1(input: &Self::Parsed) -> Result<Self::Answer1>;
+    fn part2(input: &Self::Parsed) -> Result<Self::Answer2>;
+}
+
+/// The answers to some parts of a day, with how long each step took.
+#[derive(Debug, Clone)]
+pub struct Solved {
+    pub parse_time: Duration,
+    pub answers: Vec<(String, Duration)>,
+}
+
+pub struct Day {
+    pub day: u8,
+    pub parts: u8,
+    pub params: &'static [(&'static str, &'static str)],
+    solve: fn(&str, &[u8], &Params) -> Result<Solved>,
+}
+
+impl Day {
+    const fn new<S: Solution>(day: u8, parts: u8) -> Self {
+        Self {
+            day,
+            parts,
+            params: S::PARAMS,
+            solve: solve::<S>,
+        }
+    }
+
+    /// Parses `input` and returns the answer to each of `parts`, in order.
+    pub fn solve(&self, input: &str, parts: &[u8]) -> Result<Vec<String>> {
+        let solved = self.solve_timed(input, parts, &Params::new())?;
+
+        Ok(solved.answers.into_iter().map(|(a, _)| a).collect())
+    }
+
+    /// Like [`Day::solve`], but with `--param` settings, and also times
+    /// parsing and each part separately.
+    pub fn solve_timed(&self, input: &str, parts: &[u8], params: &Params) -> Result<Solved> {
+        (self.solve)(input, parts, params)
+    }
+}
+
+fn solve<S: Solution>(input: &str, parts: &[u8], params: &Params) -> Result<Solved> {
+    params.check(S::PARAMS)?;
+
+    let start = Instant::now();
+    let mut parsed = S::parse(input).context("Could not parse input")?;
+    S::configure(&mut parsed, params)?;
+    let parse_time = start.elapsed();
+
+    let answers = parts
+        .iter()
+        .map(|part| {
+            let start = Instant::now();
+            let answer = match part {
+                1 => S::part1(&parsed)?.to_string(),
+                2 => S::part2(&parsed)?.to_string(),
+                _ => bail!("Invalid part {}", part),
+            };
+
+            Ok((answer, start.elapsed()))
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(Solved {
+        parse_time,
+        answers,
+    })
+}
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(1, 2),
+    Day::new::<day02::Day02>(2, 2),
+    Day::new::<day03::Day03>(3, 2),
+    Day::new::<day04::Day04>(4, 2),
+    Day::new::<day05::Day05>(5, 2),
+    Day::new::<day06::Day06>(6, 2),
+    Day::new::<day07::Day07>(7, 2),
+    Day::new::<day08::Day08>(8, 2),
+    Day::new::<day09::Day09>(9, 2),
+    Day::new::<day10::Day10>(10, 2),
+    Day::new::<day11::Day11>(11, 2),
+    Day::new::<day12::Day12>(12, 2),
+    Day::new::<day13::Day13>(13, 2),
+    Day::new::<day14::Day14>(14, 2),
+    Day::new::<day15::Day15>(15, 2),
+    Day::new::<day16::Day16>(16, 2),
+];
+
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
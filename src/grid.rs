@@ -0,0 +1,273 @@
+use std::{
+    fmt::Display,
+    ops::{Add, Index, IndexMut, Mul, Sub},
+};
+
+use crate::parse::ParseError;
+
+/// A position or offset on a [`Grid`]. `x` is the column and `y` the row,
+/// with `y` growing downwards as in the puzzle maps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    pub const UP: Point = Point::new(0, -1);
+    pub const DOWN: Point = Point::new(0, 1);
+    pub const LEFT: Point = Point::new(-1, 0);
+    pub const RIGHT: Point = Point::new(1, 0);
+
+    /// The four orthogonal directions.
+    pub const ORTHOGONAL: [Point; 4] = [Point::UP, Point::DOWN, Point::LEFT, Point::RIGHT];
+
+    /// The orthogonal and diagonal directions.
+    pub const ALL_DIRECTIONS: [Point; 8] = [
+        Point::new(-1, -1),
+        Point::UP,
+        Point::new(1, -1),
+        Point::LEFT,
+        Point::RIGHT,
+        Point::new(-1, 1),
+        Point::DOWN,
+        Point::new(1, 1),
+    ];
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan_distance(&self, other: Point) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: isize) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// A rectangular grid stored row by row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses a map with one character per cell. `cell` converts each
+    /// character, and any it rejects are reported as not being `expected`.
+    /// Rows must all be the same length.
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for line in input.lines() {
+            let mut row_width = 0;
+
+            for (idx, ch) in line.char_indices() {
+                let value = cell(ch).ok_or_else(|| {
+                    ParseError::new(input, &line[idx..idx + ch.len_utf8()], expected)
+                })?;
+
+                cells.push(value);
+                row_width += 1;
+            }
+
+            match width {
+                None => width = Some(row_width),
+                Some(w) if w != row_width => {
+                    return Err(ParseError::new(
+                        input,
+                        line,
+                        format!("a row of {} cells", w),
+                    ));
+                }
+                Some(_) => {}
+            }
+
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Self {
+                width,
+                height,
+                cells,
+            }),
+            _ => Err(ParseError::new(input, input, "at least one row of cells")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        (0..self.width as isize).contains(&p.x) && (0..self.height as isize).contains(&p.y)
+    }
+
+    fn index_of(&self, p: Point) -> Option<usize> {
+        if self.contains(p) {
+            Some(p.y as usize * self.width + p.x as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.index_of(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.index_of(p).map(|i| &mut self.cells[i])
+    }
+
+    /// Every point on the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width as isize;
+
+        (0..self.height as isize).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every cell on the grid with its point, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// The first point, row by row, whose cell matches `predicate`.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter().find(|(_, c)| predicate(c)).map(|(p, _)| p)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    /// The cells of row `y`, left to right. Panics if there's no such row.
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            y < self.height,
+            "row {} is outside the {}x{} grid",
+            y,
+            self.width,
+            self.height
+        );
+
+        self.cells[y * self.width..(y + 1) * self.width].iter()
+    }
+
+    /// The cells of column `x`, top to bottom. Panics if there's no such
+    /// column.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {} is outside the {}x{} grid",
+            x,
+            self.width,
+            self.height
+        );
+
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /// The in-bounds points next to `p`, not counting diagonals.
+    pub fn neighbors4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbors(p, &Point::ORTHOGONAL)
+    }
+
+    /// The in-bounds points next to `p`, including diagonals.
+    pub fn neighbors8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbors(p, &Point::ALL_DIRECTIONS)
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        p: Point,
+        directions: &'static [Point],
+    ) -> impl Iterator<Item = Point> + 'a {
+        directions
+            .iter()
+            .map(move |&d| p + d)
+            .filter(|&n| self.contains(n))
+    }
+
+    /// The points from `from` (exclusive) stepping by `direction` until the
+    /// edge of the grid.
+    pub fn ray(&self, from: Point, direction: Point) -> impl Iterator<Item = Point> + '_ {
+        std::iter::successors(Some(from + direction), move |&p| Some(p + direction))
+            .take_while(|&p| self.contains(p))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        self.get(p)
+            .unwrap_or_else(|| panic!("{} is outside the {}x{} grid", p, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("{} is outside the {}x{} grid", p, width, height))
+    }
+}
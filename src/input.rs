@@ -0,0 +1,56 @@
+use anyhow::{Context, Result};
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+/// Where a day's puzzle input is read from at runtime.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// The checked-in puzzle input under `src/inputs/`.
+    Default,
+    Path(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Parses a command line value, where `-` means stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::Path(PathBuf::from(arg))
+        }
+    }
+
+    pub fn read(&self, day: u8) -> Result<String> {
+        match self {
+            InputSource::Default => read_file(&default_path(day)),
+            InputSource::Path(path) => read_file(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut input)
+                    .context("Could not read input from stdin")?;
+
+                Ok(input)
+            }
+        }
+    }
+}
+
+/// The directory holding the checked-in puzzle inputs.
+pub fn inputs_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join("inputs")
+}
+
+pub fn default_path(day: u8) -> PathBuf {
+    inputs_dir().join(format!("day{}.txt", day))
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    std::fs::read_to_string(path)
+        .with_context(|| format!("Could not read input file {}", path.display()))
+}
@@ -0,0 +1,204 @@
+use std::ops::RangeInclusive;
+
+/// A set of integers stored as sorted, non-overlapping inclusive ranges.
+/// Ranges that touch are merged, so every set has exactly one representation.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    ranges: Vec<(isize, isize)>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds every value in `range`. Empty ranges are ignored.
+    pub fn insert(&mut self, range: RangeInclusive<isize>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        // The ranges that overlap or touch the new one are merged into it.
+        let first = self
+            .ranges
+            .partition_point(|&(_, e)| e.saturating_add(1) < start);
+        let last = self
+            .ranges
+            .partition_point(|&(s, _)| s.saturating_sub(1) <= end);
+
+        if first < last {
+            start = start.min(self.ranges[first].0);
+            end = end.max(self.ranges[last - 1].1);
+        }
+
+        self.ranges.splice(first..last, [(start, end)]);
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut union = self.clone();
+
+        for range in other.ranges() {
+            union.insert(range);
+        }
+
+        union
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(&(a_start, a_end)), Some(&(b_start, b_end))) =
+            (self.ranges.get(i), other.ranges.get(j))
+        {
+            let start = a_start.max(b_start);
+            let end = a_end.min(b_end);
+
+            if start <= end {
+                ranges.push((start, end));
+            }
+
+            if a_end < b_end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        IntervalSet { ranges }
+    }
+
+    /// The values in `self` that aren't in `other`.
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut ranges = vec![];
+        let mut others = other.ranges.iter().peekable();
+
+        for &(start, end) in &self.ranges {
+            // The first value of this range that hasn't been handled yet.
+            let mut next = Some(start);
+
+            while let (Some(from), Some(&&(o_start, o_end))) = (next, others.peek()) {
+                if o_end < from {
+                    others.next();
+                    continue;
+                }
+
+                if o_start > end {
+                    break;
+                }
+
+                if o_start > from {
+                    ranges.push((from, o_start - 1));
+                }
+
+                if o_end >= end {
+                    next = None;
+                } else {
+                    next = Some(o_end + 1);
+                    others.next();
+                }
+            }
+
+            if let Some(from) = next {
+                ranges.push((from, end));
+            }
+        }
+
+        IntervalSet { ranges }
+    }
+
+    /// The total number of values in the set.
+    pub fn len(&self) -> usize {
+        self.ranges
+            .iter()
+            .map(|&(start, end)| start.abs_diff(end) + 1)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn contains(&self, value: isize) -> bool {
+        self.contains_range(value..=value)
+    }
+
+    /// Whether every value in `range` is in the set. Empty ranges always are.
+    pub fn contains_range(&self, range: RangeInclusive<isize>) -> bool {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return true;
+        }
+
+        let idx = self.ranges.partition_point(|&(_, e)| e < start);
+
+        self.ranges
+            .get(idx)
+            .is_some_and(|&(s, e)| s <= start && end <= e)
+    }
+
+    /// Whether any value in `range` is in the set.
+    pub fn overlaps(&self, range: RangeInclusive<isize>) -> bool {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return false;
+        }
+
+        let idx = self.ranges.partition_point(|&(_, e)| e < start);
+
+        self.ranges.get(idx).is_some_and(|&(s, _)| s <= end)
+    }
+
+    pub fn is_subset(&self, other: &IntervalSet) -> bool {
+        self.ranges().all(|range| other.contains_range(range))
+    }
+
+    /// The ranges within `bounds` that aren't in the set, in order.
+    pub fn gaps(
+        &self,
+        bounds: RangeInclusive<isize>,
+    ) -> impl Iterator<Item = RangeInclusive<isize>> {
+        IntervalSet::from(bounds).difference(self).into_ranges()
+    }
+
+    pub fn ranges(&self) -> impl Iterator<Item = RangeInclusive<isize>> + '_ {
+        self.ranges.iter().map(|&(start, end)| start..=end)
+    }
+
+    pub fn into_ranges(self) -> impl Iterator<Item = RangeInclusive<isize>> {
+        self.ranges.into_iter().map(|(start, end)| start..=end)
+    }
+}
+
+impl From<RangeInclusive<isize>> for IntervalSet {
+    fn from(range: RangeInclusive<isize>) -> Self {
+        let mut set = IntervalSet::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl FromIterator<RangeInclusive<isize>> for IntervalSet {
+    /// Collects the ranges by sorting them first, which is quicker than
+    /// inserting them one at a time.
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<isize>>>(iter: I) -> Self {
+        let mut sorted: Vec<(isize, isize)> = iter
+            .into_iter()
+            .map(RangeInclusive::into_inner)
+            .filter(|(start, end)| start <= end)
+            .collect();
+        sorted.sort_unstable();
+
+        let mut ranges: Vec<(isize, isize)> = Vec::with_capacity(sorted.len());
+
+        for (start, end) in sorted {
+            match ranges.last_mut() {
+                Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+                _ => ranges.push((start, end)),
+            }
+        }
+
+        IntervalSet { ranges }
+    }
+}
@@ -0,0 +1,8 @@
+pub mod bench;
+pub mod days;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod params;
+pub mod parse;
+pub mod search;
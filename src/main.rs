@@ -1,3 +1,396 @@
-fn main() {
-    println!("Hello, world!");
+use anyhow::{bail, Context, Result};
+use aoc2022::{
+    bench::{self, Baseline, Stage, Stats},
+    days,
+    input::InputSource,
+    params::Params,
+};
+use std::{path::PathBuf, str::FromStr, time::Duration};
+
+mod tools;
+
+const USAGE: &str = "Usage:
+    aoc run --day <N> [--part <P>] [--input <PATH|->] [--param <KEY=VALUE>]...
+            [--format <text|json>]
+    aoc run --all [--format <text|json>]
+    aoc bench (--day <N> [--part <P>] [--input <PATH|->] [--param <KEY=VALUE>]... | --all)
+              [--iterations <N>] [--save <PATH>] [--baseline <PATH>] [--threshold <PCT>]
+    aoc list
+    aoc day14 animate ...
+    aoc day15 render ...
+    aoc day16 <routes|explain|dot|what-if> ...";
+
+const DEFAULT_ITERATIONS: usize = 10;
+const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// How `aoc run` prints its answers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    /// One `{day, part, answer, parse_ms, solve_ms}` object per line.
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => bail!("Unknown format {}, expected text or json", s),
+        }
+    }
+}
+
+/// Which days and parts to solve, and where their input comes from.
+#[derive(Debug)]
+struct Selection {
+    day: Option<u8>,
+    part: Option<u8>,
+    input: InputSource,
+    params: Params,
+}
+
+#[derive(Debug)]
+enum Command {
+    Run {
+        selection: Selection,
+        format: Format,
+    },
+    Bench {
+        selection: Selection,
+        iterations: usize,
+        save: Option<PathBuf>,
+        baseline: Option<PathBuf>,
+        threshold: f64,
+    },
+    List,
+    /// A day-specific command, given the arguments after its name.
+    Tool(fn(&[String]) -> Result<()>, Vec<String>),
+}
+
+impl Command {
+    fn from_args(args: &[String]) -> Result<Self> {
+        let (name, rest) = args.split_first().context(USAGE)?;
+
+        if name == "list" {
+            return Ok(Command::List);
+        }
+
+        if name == "day14" {
+            return Ok(Command::Tool(tools::day14::run, rest.to_vec()));
+        }
+
+        if name == "day15" {
+            return Ok(Command::Tool(tools::day15::run, rest.to_vec()));
+        }
+
+        if name == "day16" {
+            return Ok(Command::Tool(tools::day16::run, rest.to_vec()));
+        }
+
+        if name != "run" && name != "bench" {
+            bail!("Unknown command {}\n\n{}", name, USAGE);
+        }
+
+        let mut day = None;
+        let mut part = None;
+        let mut all = false;
+        let mut input = InputSource::Default;
+        let mut params = Params::new();
+        let mut iterations = None;
+        let mut save = None;
+        let mut baseline = None;
+        let mut threshold = None;
+        let mut format = None;
+
+        let mut flags = rest.iter();
+        while let Some(flag) = flags.next() {
+            match flag.as_str() {
+                "--day" | "-d" => day = Some(parse_flag_value(flag, flags.next())?),
+                "--part" | "-p" => part = Some(parse_flag_value(flag, flags.next())?),
+                "--all" | "-a" => all = true,
+                "--input" | "-i" => input = InputSource::from_arg(flag_value(flag, flags.next())?),
+                "--param" | "-P" => params.insert_arg(flag_value(flag, flags.next())?)?,
+                "--iterations" | "-n" => iterations = Some(parse_flag_value(flag, flags.next())?),
+                "--save" => save = Some(PathBuf::from(flag_value(flag, flags.next())?)),
+                "--baseline" => baseline = Some(PathBuf::from(flag_value(flag, flags.next())?)),
+                "--threshold" => threshold = Some(parse_flag_value(flag, flags.next())?),
+                "--format" | "-f" => format = Some(flag_value(flag, flags.next())?.parse()?),
+                _ => bail!("Unknown flag {}\n\n{}", flag, USAGE),
+            }
+        }
+
+        if all == day.is_some() {
+            bail!("Expected exactly one of --day or --all\n\n{}", USAGE);
+        }
+
+        if all && input != InputSource::Default {
+            bail!("--input can only be used with a single --day");
+        }
+
+        if all && !params.is_empty() {
+            bail!("--param can only be used with a single --day");
+        }
+
+        let selection = Selection {
+            day,
+            part,
+            input,
+            params,
+        };
+
+        if name == "run" {
+            if iterations.is_some() || save.is_some() || baseline.is_some() || threshold.is_some() {
+                bail!("--iterations, --save, --baseline and --threshold are only used by bench");
+            }
+
+            return Ok(Command::Run {
+                selection,
+                format: format.unwrap_or(Format::Text),
+            });
+        }
+
+        if format.is_some() {
+            bail!("--format is only used by run");
+        }
+
+        let iterations = iterations.unwrap_or(DEFAULT_ITERATIONS);
+        if iterations == 0 {
+            bail!("--iterations must be at least 1");
+        }
+
+        Ok(Command::Bench {
+            selection,
+            iterations,
+            save,
+            baseline,
+            threshold: threshold.unwrap_or(DEFAULT_THRESHOLD),
+        })
+    }
+}
+
+fn flag_value<'a>(flag: &str, value: Option<&'a String>) -> Result<&'a String> {
+    value.with_context(|| format!("Missing value for {}", flag))
+}
+
+fn parse_flag_value<T: FromStr>(flag: &str, value: Option<&String>) -> Result<T> {
+    let value = flag_value(flag, value)?;
+
+    value
+        .parse::<T>()
+        .ok()
+        .with_context(|| format!("Invalid value for {}: {}", flag, value))
+}
+
+/// Looks up the selected days and the parts to solve for each of them.
+fn select(selection: &Selection) -> Result<Vec<(&'static days::Day, Vec<u8>)>> {
+    let selected: Vec<&days::Day> = match selection.day {
+        Some(d) => vec![days::find(d).with_context(|| format!("No solutions for day {}", d))?],
+        None => days::DAYS.iter().collect(),
+    };
+
+    selected
+        .into_iter()
+        .map(|day| {
+            let parts: Vec<u8> = match selection.part {
+                Some(p) if p == 0 || p > day.parts => {
+                    bail!("No solution for day {} part {}", day.day, p)
+                }
+                Some(p) => vec![p],
+                None => (1..=day.parts).collect(),
+            };
+
+            Ok((day, parts))
+        })
+        .collect()
+}
+
+fn run(selection: &Selection, format: Format) -> Result<()> {
+    let mut answers: Vec<(u8, u8, String)> = vec![];
+
+    for (day, parts) in select(selection)? {
+        let input = selection.input.read(day.day)?;
+        let solved = day
+            .solve_timed(&input, &parts, &selection.params)
+            .with_context(|| format!("Day {} failed", day.day))?;
+
+        for (part, (answer, solve_time)) in parts.into_iter().zip(solved.answers) {
+            if format == Format::Json {
+                println!(
+                    "{{\"day\":{},\"part\":{},\"answer\":{},\"parse_ms\":{:.3},\"solve_ms\":{:.3}}}",
+                    day.day,
+                    part,
+                    json_string(&answer),
+                    solved.parse_time.as_secs_f64() * 1000.0,
+                    solve_time.as_secs_f64() * 1000.0,
+                );
+            }
+
+            answers.push((day.day, part, answer));
+        }
+    }
+
+    if format == Format::Json {
+        return Ok(());
+    }
+
+    if let [(_, _, answer)] = answers.as_slice() {
+        println!("{}", answer);
+        return Ok(());
+    }
+
+    for (day, part, answer) in answers {
+        let label = format!("Day {} Part {}:", day, part);
+
+        if answer.contains('\n') {
+            println!("{}\n{}", label, answer.trim_end());
+        } else {
+            println!("{} {}", label, answer);
+        }
+    }
+
+    Ok(())
+}
+
+/// Quotes `s` as a JSON string.
+fn json_string(s: &str) -> String {
+    let mut quoted = String::from("\"");
+
+    for c in s.chars() {
+        match c {
+            '"' => quoted += "\\\"",
+            '\\' => quoted += "\\\\",
+            '\n' => quoted += "\\n",
+            '\r' => quoted += "\\r",
+            '\t' => quoted += "\\t",
+            c if (c as u32) < 0x20 => quoted += &format!("\\u{:04x}", c as u32),
+            c => quoted.push(c),
+        }
+    }
+
+    quoted.push('"');
+    quoted
+}
+
+fn bench(
+    selection: &Selection,
+    iterations: usize,
+    save: Option<&PathBuf>,
+    baseline: Option<&PathBuf>,
+    threshold: f64,
+) -> Result<()> {
+    let baseline = baseline.map(|path| Baseline::read(path)).transpose()?;
+
+    // Days that aren't benched this time keep their saved timings.
+    let mut results = match save {
+        Some(path) if path.exists() => Baseline::read(path)?,
+        _ => Baseline::default(),
+    };
+    let mut regressions = 0;
+
+    for (day, parts) in select(selection)? {
+        let input = selection.input.read(day.day)?;
+
+        let mut samples: Vec<(Stage, Vec<Duration>)> = std::iter::once(Stage::Parse)
+            .chain(parts.iter().map(|&p| Stage::Part(p)))
+            .map(|stage| (stage, Vec::with_capacity(iterations)))
+            .collect();
+
+        for _ in 0..iterations {
+            let solved = day
+                .solve_timed(&input, &parts, &selection.params)
+                .with_context(|| format!("Day {} failed", day.day))?;
+
+            let timings = std::iter::once(solved.parse_time)
+                .chain(solved.answers.into_iter().map(|(_, t)| t));
+
+            for ((_, stage_samples), time) in samples.iter_mut().zip(timings) {
+                stage_samples.push(time);
+            }
+        }
+
+        for (stage, stage_samples) in samples {
+            let stats = Stats::from_samples(&stage_samples).context("No timings recorded")?;
+            results.insert(day.day, stage, stats.median);
+
+            let mut line = format!(
+                "day {:>2} {:<6} min {:>10} median {:>10} max {:>10}",
+                day.day,
+                stage,
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.max),
+            );
+
+            if let Some(previous) = baseline.as_ref().and_then(|b| b.get(day.day, stage)) {
+                let change = bench::change_percent(previous, stats.median);
+                line += &format!(
+                    "  baseline {:>10} {:>+7.1}%",
+                    format!("{:.2?}", previous),
+                    change
+                );
+
+                if change > threshold {
+                    line += "  REGRESSION";
+                    regressions += 1;
+                }
+            }
+
+            println!("{}", line);
+        }
+    }
+
+    if let Some(path) = save {
+        results.write(path)?;
+    }
+
+    if regressions > 0 {
+        bail!(
+            "{} timing(s) regressed by more than {}% against the baseline",
+            regressions,
+            threshold
+        );
+    }
+
+    Ok(())
+}
+
+fn list() {
+    for day in days::DAYS {
+        for part in 1..=day.parts {
+            println!("day {:>2} part {}", day.day, part);
+        }
+
+        for (name, description) in day.params {
+            println!("    --param {}=...  {}", name, description);
+        }
+    }
+}
+
+fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    match Command::from_args(&args)? {
+        Command::Run { selection, format } => run(&selection, format),
+        Command::Bench {
+            selection,
+            iterations,
+            save,
+            baseline,
+            threshold,
+        } => bench(
+            &selection,
+            iterations,
+            save.as_ref(),
+            baseline.as_ref(),
+            threshold,
+        ),
+        Command::List => {
+            list();
+            Ok(())
+        }
+        Command::Tool(run_tool, args) => run_tool(&args),
+    }
 }
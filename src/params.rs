@@ -0,0 +1,64 @@
+use anyhow::{anyhow, bail, Context, Result};
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+/// Day-specific settings, given on the command line as `--param key=value`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<String, String>,
+}
+
+impl Params {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a `key=value` argument.
+    pub fn insert_arg(&mut self, arg: &str) -> Result<()> {
+        let (key, value) = arg
+            .split_once('=')
+            .with_context(|| format!("Expected a parameter as key=value, found {}", arg))?;
+
+        self.insert(key, value);
+
+        Ok(())
+    }
+
+    pub fn insert(&mut self, key: &str, value: &str) {
+        self.values.insert(key.to_string(), value.to_string());
+    }
+
+    /// Parses the value of `key`, or returns `None` if it wasn't given.
+    pub fn get<T>(&self, key: &str) -> Result<Option<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.values
+            .get(key)
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|e| anyhow!("Invalid value for parameter {}: {} ({})", key, value, e))
+            })
+            .transpose()
+    }
+
+    /// Fails if any parameter isn't one of `accepted`, given as (name, description) pairs.
+    pub fn check(&self, accepted: &[(&str, &str)]) -> Result<()> {
+        match self
+            .keys()
+            .find(|key| !accepted.iter().any(|(name, _)| name == key))
+        {
+            Some(key) => bail!("Unknown parameter {}", key),
+            None => Ok(()),
+        }
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.values.keys().map(String::as_str)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
@@ -0,0 +1,127 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+/// A malformed piece of puzzle input, with the position it was found at.
+///
+/// `line` and `column` are 1-based and count characters, so they can be
+/// pasted straight into an editor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Creates an error for `found`, which should be a slice of `source`. The
+    /// line and column are worked out from where `found` sits in `source`;
+    /// if it isn't part of `source` the error points at the start.
+    pub fn new(source: &str, found: &str, expected: impl Into<String>) -> Self {
+        let (line, column) = match offset_in(source, found) {
+            Some(offset) => {
+                let before = &source[..offset];
+                let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+                (
+                    before.matches('\n').count() + 1,
+                    before[line_start..].chars().count() + 1,
+                )
+            }
+            None => (1, 1),
+        };
+
+        Self {
+            line,
+            column,
+            text: found.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Moves the error down by `lines`, for errors raised while parsing one
+    /// line or block of a larger input.
+    pub fn offset(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {:?}",
+            self.line, self.column, self.expected, self.text
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+fn offset_in(source: &str, found: &str) -> Option<usize> {
+    let start = source.as_ptr() as usize;
+    let found_start = found.as_ptr() as usize;
+
+    if found_start >= start && found_start + found.len() <= start + source.len() {
+        Some(found_start - start)
+    } else {
+        None
+    }
+}
+
+/// Parses `found` (a slice of `source`) as a `T`, reporting `expected` on failure.
+pub fn parse_value<T: FromStr>(source: &str, found: &str, expected: &str) -> Result<T, ParseError> {
+    found
+        .parse::<T>()
+        .map_err(|_| ParseError::new(source, found, expected))
+}
+
+/// Splits `found` (a slice of `source`) around the first `delimiter`.
+pub fn split_once<'a>(
+    source: &str,
+    found: &'a str,
+    delimiter: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    found
+        .split_once(delimiter)
+        .ok_or_else(|| ParseError::new(source, found, format!("{:?}", delimiter)))
+}
+
+/// Strips `prefix` from the start of `found` (a slice of `source`).
+pub fn strip_prefix<'a>(source: &str, found: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    found
+        .strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(source, found, format!("{:?}", prefix)))
+}
+
+/// Parses every line of `input` as a `T`, with errors numbered from the top of `input`.
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, l)| l.parse::<T>().map_err(|e| e.offset(i)))
+        .collect()
+}
+
+/// Parses every blank-line separated block of `input` as a `T`, with errors
+/// numbered from the top of `input`.
+pub fn parse_blocks<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    let mut line = 0;
+
+    input
+        .trim_end()
+        .split("\n\n")
+        .map(|block| {
+            let parsed = block.parse::<T>().map_err(|e| e.offset(line));
+            line += block.lines().count() + 1;
+
+            parsed
+        })
+        .collect()
+}
@@ -0,0 +1,214 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+/// A graph described by the edges leaving each node, each with a cost.
+///
+/// Closures `Fn(&N) -> impl IntoIterator<Item = (N, usize)>` implement this,
+/// so a search can be run straight over a grid or map without a wrapper type.
+pub trait Neighbors<N> {
+    type Iter: IntoIterator<Item = (N, usize)>;
+
+    fn neighbors(&self, node: &N) -> Self::Iter;
+}
+
+impl<N, F, I> Neighbors<N> for F
+where
+    F: Fn(&N) -> I,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    type Iter = I;
+
+    fn neighbors(&self, node: &N) -> I {
+        self(node)
+    }
+}
+
+/// A route through a graph, including both ends.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N> {
+    pub cost: usize,
+    pub nodes: Vec<N>,
+}
+
+/// The cheapest route found from the start to every node a search reached.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<N> {
+    best: HashMap<N, (usize, Option<N>)>,
+}
+
+impl<N: Clone + Eq + Hash> ShortestPaths<N> {
+    fn new(start: N) -> Self {
+        Self {
+            best: HashMap::from([(start, (0, None))]),
+        }
+    }
+
+    pub fn cost(&self, node: &N) -> Option<usize> {
+        self.best.get(node).map(|&(cost, _)| cost)
+    }
+
+    /// The node before `node` on its cheapest path, or `None` for the start
+    /// and nodes that were never reached.
+    pub fn parent(&self, node: &N) -> Option<&N> {
+        self.best.get(node)?.1.as_ref()
+    }
+
+    /// Walks back from `node` to the start, or returns `None` if `node` was
+    /// never reached.
+    pub fn path(&self, node: &N) -> Option<Path<N>> {
+        let cost = self.cost(node)?;
+        let mut nodes = vec![node.clone()];
+
+        while let Some((_, Some(parent))) = self.best.get(nodes.last().unwrap()) {
+            nodes.push(parent.clone());
+        }
+
+        nodes.reverse();
+
+        Some(Path { cost, nodes })
+    }
+
+    /// Every node the search reached, including the start, with its cost.
+    pub fn reached(&self) -> impl Iterator<Item = (&N, usize)> {
+        self.best.iter().map(|(node, &(cost, _))| (node, cost))
+    }
+}
+
+/// Breadth-first search from `start` to the nearest node matching `is_goal`,
+/// counting every edge as one step whatever its cost.
+pub fn bfs<N, G>(graph: &G, start: N, is_goal: impl FnMut(&N) -> bool) -> Option<Path<N>>
+where
+    N: Clone + Eq + Hash,
+    G: Neighbors<N>,
+{
+    let (paths, goal) = breadth_first(graph, start, is_goal);
+
+    paths.path(&goal?)
+}
+
+/// Breadth-first search from `start` to every node it can reach.
+pub fn bfs_all<N, G>(graph: &G, start: N) -> ShortestPaths<N>
+where
+    N: Clone + Eq + Hash,
+    G: Neighbors<N>,
+{
+    breadth_first(graph, start, |_| false).0
+}
+
+fn breadth_first<N, G>(
+    graph: &G,
+    start: N,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> (ShortestPaths<N>, Option<N>)
+where
+    N: Clone + Eq + Hash,
+    G: Neighbors<N>,
+{
+    let mut paths = ShortestPaths::new(start.clone());
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            return (paths, Some(node));
+        }
+
+        let steps = paths.best[&node].0 + 1;
+
+        for (next, _) in graph.neighbors(&node) {
+            if let Entry::Vacant(e) = paths.best.entry(next.clone()) {
+                e.insert((steps, Some(node.clone())));
+                queue.push_back(next);
+            }
+        }
+    }
+
+    (paths, None)
+}
+
+/// The cheapest path from `start` to a node matching `is_goal`.
+pub fn dijkstra<N, G>(graph: &G, start: N, is_goal: impl FnMut(&N) -> bool) -> Option<Path<N>>
+where
+    N: Clone + Eq + Hash,
+    G: Neighbors<N>,
+{
+    astar(graph, start, is_goal, |_| 0)
+}
+
+/// The cheapest path from `start` to every node it can reach.
+pub fn dijkstra_all<N, G>(graph: &G, start: N) -> ShortestPaths<N>
+where
+    N: Clone + Eq + Hash,
+    G: Neighbors<N>,
+{
+    best_first(graph, start, |_| false, |_| 0).0
+}
+
+/// The cheapest path from `start` to a node matching `is_goal`, guided by
+/// `heuristic`. The result is only guaranteed to be the cheapest if the
+/// heuristic never overestimates the remaining cost.
+pub fn astar<N, G>(
+    graph: &G,
+    start: N,
+    is_goal: impl FnMut(&N) -> bool,
+    heuristic: impl FnMut(&N) -> usize,
+) -> Option<Path<N>>
+where
+    N: Clone + Eq + Hash,
+    G: Neighbors<N>,
+{
+    let (paths, goal) = best_first(graph, start, is_goal, heuristic);
+
+    paths.path(&goal?)
+}
+
+fn best_first<N, G>(
+    graph: &G,
+    start: N,
+    mut is_goal: impl FnMut(&N) -> bool,
+    mut heuristic: impl FnMut(&N) -> usize,
+) -> (ShortestPaths<N>, Option<N>)
+where
+    N: Clone + Eq + Hash,
+    G: Neighbors<N>,
+{
+    let mut paths = ShortestPaths::new(start.clone());
+
+    // Nodes don't have to be `Ord`, so the heap holds indexes into `queued`.
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+    let mut queued = vec![start];
+
+    while let Some(Reverse((_, cost, idx))) = heap.pop() {
+        let node = queued[idx].clone();
+
+        if cost > paths.best[&node].0 {
+            continue;
+        }
+
+        if is_goal(&node) {
+            return (paths, Some(node));
+        }
+
+        for (next, step) in graph.neighbors(&node) {
+            let next_cost = cost + step;
+
+            if paths.cost(&next).is_some_and(|c| c <= next_cost) {
+                continue;
+            }
+
+            paths
+                .best
+                .insert(next.clone(), (next_cost, Some(node.clone())));
+            heap.push(Reverse((
+                next_cost + heuristic(&next),
+                next_cost,
+                queued.len(),
+            )));
+            queued.push(next);
+        }
+    }
+
+    (paths, None)
+}
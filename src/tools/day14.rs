@@ -0,0 +1,240 @@
+use anyhow::{bail, Context, Result};
+use aoc2022::{
+    days::{
+        day14::{Cave, Day14, Fall},
+        Solution,
+    },
+    grid::Point,
+    input::InputSource,
+    params::Params,
+};
+use std::{io::Write, path::PathBuf, str::FromStr, thread, time::Duration};
+
+use crate::{flag_value, parse_flag_value};
+
+const USAGE: &str = "Usage:
+    aoc day14 animate [--part <1|2>] [--input <PATH|->] [--param <KEY=VALUE>]...
+                      [--every <N> | --steps] [--fps <N>] [--crop <WIDTH,HEIGHT>]
+                      [--frames <DIR>] [--scale <N>]";
+
+const DEFAULT_FPS: f64 = 30.0;
+const DEFAULT_SCALE: usize = 4;
+
+/// Clears the terminal and moves the cursor to the top left.
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+/// A viewport `width` spots across, centred on the sand source, and `height`
+/// spots down from it.
+#[derive(Debug, Clone, Copy)]
+struct Crop {
+    width: usize,
+    height: usize,
+}
+
+impl FromStr for Crop {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (width, height) = s.split_once(',').context("expected WIDTH,HEIGHT")?;
+        let crop = Crop {
+            width: width.parse()?,
+            height: height.parse()?,
+        };
+
+        if crop.width == 0 || crop.height == 0 {
+            bail!("expected a width and height of at least 1");
+        }
+
+        Ok(crop)
+    }
+}
+
+impl Crop {
+    fn around(&self, source: Point) -> (Point, Point) {
+        let min = Point::new(source.x - self.width as isize / 2, source.y);
+
+        (
+            min,
+            min + Point::new(self.width as isize - 1, self.height as isize - 1),
+        )
+    }
+}
+
+/// Where frames go: redrawn in the terminal at a steady rate, or written to
+/// a directory as numbered PPM images.
+struct Animation {
+    min: Point,
+    max: Point,
+    delay: Option<Duration>,
+    frames_dir: Option<PathBuf>,
+    scale: usize,
+    frames: usize,
+}
+
+impl Animation {
+    fn draw(&mut self, cave: &Cave, falling: Option<Point>, rested: usize) -> Result<()> {
+        self.frames += 1;
+
+        if let Some(dir) = &self.frames_dir {
+            let path = dir.join(format!("frame{:06}.ppm", self.frames));
+            let image = cave.render_ppm(self.min, self.max, self.scale, falling);
+
+            return std::fs::write(&path, image)
+                .with_context(|| format!("Could not write {}", path.display()));
+        }
+
+        let mut stdout = std::io::stdout().lock();
+        writeln!(
+            stdout,
+            "{}{}Grains at rest: {}",
+            CLEAR_SCREEN,
+            cave.render_ascii(self.min, self.max, falling),
+            rested
+        )?;
+        stdout.flush()?;
+
+        if let Some(delay) = self.delay {
+            thread::sleep(delay);
+        }
+
+        Ok(())
+    }
+}
+
+/// Pours sand into the cave for one part, drawing a frame after every
+/// `--every` grains come to rest, or after every step of every grain with
+/// `--steps`. Frames show the whole of where sand can settle unless they're
+/// cropped around the source, and are drawn in the terminal unless
+/// `--frames` is given, which writes them out as images instead.
+pub fn run(args: &[String]) -> Result<()> {
+    let (name, rest) = args.split_first().context(USAGE)?;
+
+    if name != "animate" {
+        bail!("Unknown day 14 command {}\n\n{}", name, USAGE);
+    }
+
+    let mut input = InputSource::Default;
+    let mut params = Params::new();
+    let mut part: u8 = 1;
+    let mut every: Option<usize> = None;
+    let mut steps = false;
+    let mut fps = DEFAULT_FPS;
+    let mut crop: Option<Crop> = None;
+    let mut frames_dir: Option<PathBuf> = None;
+    let mut scale = DEFAULT_SCALE;
+
+    let mut flags = rest.iter();
+    while let Some(flag) = flags.next() {
+        match flag.as_str() {
+            "--input" | "-i" => input = InputSource::from_arg(flag_value(flag, flags.next())?),
+            "--param" | "-P" => params.insert_arg(flag_value(flag, flags.next())?)?,
+            "--part" | "-p" => part = parse_flag_value(flag, flags.next())?,
+            "--every" => every = Some(parse_flag_value(flag, flags.next())?),
+            "--steps" => steps = true,
+            "--fps" => fps = parse_flag_value(flag, flags.next())?,
+            "--crop" => crop = Some(parse_flag_value(flag, flags.next())?),
+            "--frames" => frames_dir = Some(PathBuf::from(flag_value(flag, flags.next())?)),
+            "--scale" => scale = parse_flag_value(flag, flags.next())?,
+            _ => bail!("Unknown flag {}\n\n{}", flag, USAGE),
+        }
+    }
+
+    if part != 1 && part != 2 {
+        bail!("Day 14 has parts 1 and 2, not {}", part);
+    }
+
+    if steps && every.is_some() {
+        bail!("--every and --steps can't be used together");
+    }
+
+    if every == Some(0) || scale == 0 {
+        bail!("--every and --scale must be at least 1");
+    }
+
+    if !(fps >= 0.0 && fps.is_finite()) {
+        bail!("--fps must be a number of frames per second, or 0 for no delay");
+    }
+
+    params.check(Day14::PARAMS)?;
+
+    let mut scan = Day14::parse(&input.read(14)?).context("Could not parse input")?;
+    Day14::configure(&mut scan, &params)?;
+
+    if let Some(dir) = &frames_dir {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Could not create {}", dir.display()))?;
+    }
+
+    let mut cave = scan.cave(part == 2);
+    let (min, max) = match crop {
+        Some(crop) => crop.around(cave.sand_source),
+        None => cave.viewport(),
+    };
+
+    let mut animation = Animation {
+        min,
+        max,
+        delay: (fps > 0.0).then(|| Duration::from_secs_f64(1.0 / fps)),
+        frames_dir,
+        scale,
+        frames: 0,
+    };
+
+    let rested = pour(&mut cave, &mut animation, every.unwrap_or(1), steps)?;
+
+    match &animation.frames_dir {
+        Some(dir) => println!(
+            "Wrote {} frames to {} ({} grains at rest)",
+            animation.frames,
+            dir.display(),
+            rested
+        ),
+        None => println!("{} frames", animation.frames),
+    }
+
+    Ok(())
+}
+
+/// Drops grains until one falls into the void or the source is blocked,
+/// drawing frames along the way and always drawing the last one. Returns how
+/// many grains came to rest.
+fn pour(cave: &mut Cave, animation: &mut Animation, every: usize, steps: bool) -> Result<usize> {
+    let mut rested = 0;
+    let mut drawn = false;
+
+    'grains: loop {
+        let mut sand = cave.sand_source;
+
+        loop {
+            match cave.fall(sand) {
+                Fall::To(next) => {
+                    sand = next;
+
+                    if steps {
+                        animation.draw(cave, Some(sand), rested)?;
+                        drawn = false;
+                    }
+                }
+                Fall::Rest => break,
+                Fall::Void => break 'grains,
+            }
+        }
+
+        rested += 1;
+        drawn = steps || rested % every == 0;
+
+        if drawn {
+            animation.draw(cave, None, rested)?;
+        }
+
+        if sand == cave.sand_source {
+            break;
+        }
+    }
+
+    if !drawn {
+        animation.draw(cave, None, rested)?;
+    }
+
+    Ok(rested)
+}
@@ -0,0 +1,93 @@
+use anyhow::{bail, Context, Result};
+use aoc2022::{
+    days::{
+        day15::{self, Day15, Window},
+        Solution,
+    },
+    input::InputSource,
+    params::Params,
+};
+use std::path::PathBuf;
+
+use crate::{flag_value, parse_flag_value};
+
+const USAGE: &str = "Usage:
+    aoc day15 render [--input <PATH|->] [--param <KEY=VALUE>]... [--window <X0,Y0,X1,Y1>]
+                     [--scale <N>] [--ppm <PATH>]";
+
+/// How many characters or pixels across a map is when no scale is given.
+const MAX_ASCII_SIZE: usize = 100;
+const MAX_IMAGE_SIZE: usize = 1000;
+
+/// Prints the sensor coverage as ASCII, or with `--ppm` writes it as an image.
+/// ASCII maps default to a window around every sensor and beacon, and images
+/// to part 2's search area with the gap marked.
+pub fn run(args: &[String]) -> Result<()> {
+    let (name, rest) = args.split_first().context(USAGE)?;
+
+    if name != "render" {
+        bail!("Unknown day 15 command {}\n\n{}", name, USAGE);
+    }
+
+    let mut input = InputSource::Default;
+    let mut params = Params::new();
+    let mut window: Option<Window> = None;
+    let mut scale: Option<usize> = None;
+    let mut ppm: Option<PathBuf> = None;
+
+    let mut flags = rest.iter();
+    while let Some(flag) = flags.next() {
+        match flag.as_str() {
+            "--input" | "-i" => input = InputSource::from_arg(flag_value(flag, flags.next())?),
+            "--param" | "-P" => params.insert_arg(flag_value(flag, flags.next())?)?,
+            "--window" => window = Some(parse_flag_value(flag, flags.next())?),
+            "--scale" => scale = Some(parse_flag_value(flag, flags.next())?),
+            "--ppm" => ppm = Some(PathBuf::from(flag_value(flag, flags.next())?)),
+            _ => bail!("Unknown flag {}\n\n{}", flag, USAGE),
+        }
+    }
+
+    if scale == Some(0) {
+        bail!("--scale must be at least 1");
+    }
+
+    params.check(Day15::PARAMS)?;
+
+    let mut survey = Day15::parse(&input.read(15)?).context("Could not parse input")?;
+    Day15::configure(&mut survey, &params)?;
+
+    match ppm {
+        Some(path) => {
+            let window = window.unwrap_or(Window {
+                min: day15::Coord { x: 0, y: 0 },
+                max: day15::Coord {
+                    x: survey.bound,
+                    y: survey.bound,
+                },
+            });
+            let scale = scale.unwrap_or_else(|| window.scale_to_fit(MAX_IMAGE_SIZE));
+            let gap = survey.find_beacon().ok();
+
+            let image = day15::render_ppm(&survey.sensors, window, scale, gap);
+            std::fs::write(&path, image)
+                .with_context(|| format!("Could not write {}", path.display()))?;
+
+            let (width, height) = window.scaled_size(scale);
+            println!(
+                "Wrote a {}x{} image at 1:{} to {}",
+                width,
+                height,
+                scale,
+                path.display()
+            );
+        }
+        None => {
+            let window = window.unwrap_or_else(|| Window::around(&survey.sensors));
+            let scale = scale.unwrap_or_else(|| window.scale_to_fit(MAX_ASCII_SIZE));
+
+            print!("{}", day15::render_ascii(&survey.sensors, window, scale));
+        }
+    }
+
+    Ok(())
+}
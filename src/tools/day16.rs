@@ -0,0 +1,166 @@
+use anyhow::{bail, Context, Result};
+use aoc2022::{
+    days::{
+        day16::{self, CompressedNetwork, Day16, Network, Plan, Searched, State, Volcano},
+        Solution,
+    },
+    input::InputSource,
+    params::Params,
+};
+
+use crate::{flag_value, parse_flag_value};
+
+const USAGE: &str = "Usage:
+    aoc day16 routes [--input <PATH|->] [--param <KEY=VALUE>]...
+    aoc day16 explain [--part <1|2>] [--input <PATH|->] [--param <KEY=VALUE>]...
+    aoc day16 dot [--part <1|2>] [--input <PATH|->] [--param <KEY=VALUE>]...
+    aoc day16 what-if [--input <PATH|->] [--param <KEY=VALUE>]...";
+
+/// `routes` prints the best route on your own, then the best pair of routes
+/// with the elephant, each as the valves opened in order, with how many
+/// states the search visited. `explain` walks through one part's best plan
+/// minute by minute, like the puzzle's example. `dot` prints the network as
+/// a Graphviz graph, with a part's best plan drawn over it if one is given.
+/// `what-if` prints the best pressure for every time budget up to `time`.
+pub fn run(args: &[String]) -> Result<()> {
+    let (name, rest) = args.split_first().context(USAGE)?;
+
+    if !["routes", "explain", "dot", "what-if"].contains(&name.as_str()) {
+        bail!("Unknown day 16 command {}\n\n{}", name, USAGE);
+    }
+
+    let mut input = InputSource::Default;
+    let mut params = Params::new();
+    let mut part: Option<u8> = None;
+
+    let mut flags = rest.iter();
+    while let Some(flag) = flags.next() {
+        match flag.as_str() {
+            "--input" | "-i" => input = InputSource::from_arg(flag_value(flag, flags.next())?),
+            "--param" | "-P" => params.insert_arg(flag_value(flag, flags.next())?)?,
+            "--part" | "-p" if name == "explain" || name == "dot" => {
+                part = Some(parse_flag_value(flag, flags.next())?)
+            }
+            _ => bail!("Unknown flag {}\n\n{}", flag, USAGE),
+        }
+    }
+
+    params.check(Day16::PARAMS)?;
+
+    let mut volcano = Day16::parse(&input.read(16)?).context("Could not parse input")?;
+    Day16::configure(&mut volcano, &params)?;
+
+    let network = volcano.compress()?;
+
+    if name == "explain" {
+        let (max_time, plans) = best_plans(&volcano, &network, part.unwrap_or(1))?;
+        let plans: Vec<(&str, &Plan)> = plans.iter().map(|(who, plan)| (*who, plan)).collect();
+
+        print!("{}", day16::narrate(&volcano.network, max_time, &plans));
+        return Ok(());
+    }
+
+    if name == "dot" {
+        let plans = match part {
+            Some(part) => best_plans(&volcano, &network, part)?.1,
+            None => vec![],
+        };
+        let plans: Vec<(&str, &Plan)> = plans.iter().map(|(who, plan)| (*who, plan)).collect();
+
+        print!("{}", volcano.network.to_dot(&plans));
+        return Ok(());
+    }
+
+    if name == "what-if" {
+        print_budgets(&network, volcano.time);
+        return Ok(());
+    }
+
+    let Searched { best, nodes } = day16::best_alone(&network, volcano.time, volcano.method);
+    println!(
+        "Alone for {} minutes: {} ({} states searched)",
+        volcano.time, best.pressure, nodes
+    );
+    print_route(&volcano.network, "You", &best);
+
+    let Searched { best, nodes } =
+        day16::best_with_elephant(&network, volcano.time_with_elephant(), volcano.method);
+    let (you, elephant) = best;
+    println!(
+        "With the elephant for {} minutes: {} ({} states searched)",
+        volcano.time_with_elephant(),
+        you.pressure + elephant.pressure,
+        nodes
+    );
+    print_route(&volcano.network, "You", &you);
+    print_route(&volcano.network, "Elephant", &elephant);
+
+    Ok(())
+}
+
+/// The time limit for `part`, and the best plan for each of you who's
+/// opening valves in it.
+fn best_plans(
+    volcano: &Volcano,
+    network: &CompressedNetwork,
+    part: u8,
+) -> Result<(u64, Vec<(&'static str, Plan)>)> {
+    match part {
+        1 => {
+            let you = day16::best_alone(network, volcano.time, volcano.method).best;
+
+            Ok((volcano.time, vec![("You", volcano.network.plan(&you))]))
+        }
+        2 => {
+            let time = volcano.time_with_elephant();
+            let (you, elephant) = day16::best_with_elephant(network, time, volcano.method).best;
+
+            Ok((
+                time,
+                vec![
+                    ("You", volcano.network.plan(&you)),
+                    ("The elephant", volcano.network.plan(&elephant)),
+                ],
+            ))
+        }
+        _ => bail!("Day 16 has parts 1 and 2, not {}", part),
+    }
+}
+
+/// Prints the best pressure alone and with the elephant for every time
+/// budget up to `max_time`, all from one search. Both of you get the whole
+/// budget here, with no time taken off for teaching the elephant.
+fn print_budgets(network: &CompressedNetwork, max_time: u64) {
+    let Searched { best, nodes } = day16::best_pressure_per_budget(network, max_time);
+
+    println!("minutes  alone  with elephant");
+
+    for (budget, pressures) in best.iter().enumerate().skip(1) {
+        let alone = pressures.values().max().unwrap();
+        let (you, elephant) = day16::best_disjoint_pair(pressures);
+
+        println!(
+            "{:>7}  {:>5}  {:>13}",
+            budget,
+            alone,
+            pressures[&you] + pressures[&elephant]
+        );
+    }
+
+    println!("({} states searched)", nodes);
+}
+
+fn print_route(network: &Network, who: &str, state: &State) {
+    let route: Vec<&str> = [state.network.keys[0]]
+        .iter()
+        .chain(&state.route)
+        .map(|&key| network.name(key))
+        .collect();
+
+    println!(
+        "    {:<9} {} ({})",
+        format!("{}:", who),
+        route.join(" -> "),
+        state.pressure
+    );
+}
@@ -0,0 +1,5 @@
+//! Extra commands for looking into a single day, beyond printing its answers.
+
+pub mod day14;
+pub mod day15;
+pub mod day16;
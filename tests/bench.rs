@@ -0,0 +1,69 @@
+use aoc2022::bench::{self, Baseline, Stage, Stats};
+use std::{path::PathBuf, time::Duration};
+
+fn ms(n: u64) -> Duration {
+    Duration::from_millis(n)
+}
+
+/// A file in the temp directory, named after the test so tests running at
+/// the same time don't share one.
+fn temp_file(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("aoc2022-{}-{}.txt", name, std::process::id()))
+}
+
+#[test]
+fn stats_summarize_samples() {
+    assert_eq!(Stats::from_samples(&[]), None);
+
+    assert_eq!(
+        Stats::from_samples(&[ms(5), ms(1), ms(3)]),
+        Some(Stats {
+            min: ms(1),
+            median: ms(3),
+            max: ms(5),
+        })
+    );
+
+    // With an even number of samples the upper of the middle two is used.
+    let stats = Stats::from_samples(&[ms(4), ms(1), ms(3), ms(2)]).unwrap();
+    assert_eq!((stats.min, stats.median, stats.max), (ms(1), ms(3), ms(4)));
+}
+
+#[test]
+fn baselines_survive_a_round_trip() {
+    let path = temp_file("baseline-round-trip");
+
+    let mut baseline = Baseline::default();
+    baseline.insert(1, Stage::Parse, Duration::from_nanos(1234));
+    baseline.insert(1, Stage::Part(2), ms(7));
+    baseline.insert(16, Stage::Part(1), Duration::from_secs(3));
+    baseline.write(&path).unwrap();
+
+    let read = Baseline::read(&path);
+    std::fs::remove_file(&path).unwrap();
+    let read = read.unwrap();
+
+    assert_eq!(read.get(1, Stage::Parse), Some(Duration::from_nanos(1234)));
+    assert_eq!(read.get(1, Stage::Part(2)), Some(ms(7)));
+    assert_eq!(read.get(16, Stage::Part(1)), Some(Duration::from_secs(3)));
+    assert_eq!(read.get(1, Stage::Part(1)), None);
+}
+
+#[test]
+fn bad_baseline_lines_are_reported() {
+    let path = temp_file("baseline-bad-line");
+    std::fs::write(&path, "# comment\n\n3 part1 100\n3 part9x 100\n").unwrap();
+
+    let read = Baseline::read(&path);
+    std::fs::remove_file(&path).unwrap();
+    let error = format!("{:#}", read.unwrap_err());
+
+    assert!(error.starts_with("Invalid line 4"), "{}", error);
+}
+
+#[test]
+fn change_is_a_percentage_of_the_baseline() {
+    assert!((bench::change_percent(ms(100), ms(110)) - 10.0).abs() < 1e-9);
+    assert!(bench::change_percent(ms(100), ms(50)) < 0.0);
+    assert_eq!(bench::change_percent(Duration::ZERO, ms(5)), 0.0);
+}
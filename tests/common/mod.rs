@@ -0,0 +1,48 @@
+//! Helpers shared by the integration tests. Not every test uses all of them.
+#![allow(dead_code)]
+
+use aoc2022::{days, params::Params};
+use std::path::{Path, PathBuf};
+
+pub fn examples_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("examples")
+}
+
+/// A file from `tests/examples/`.
+pub fn read_example(name: &str) -> String {
+    let path = examples_dir().join(name);
+
+    std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("Could not read {}: {}", path.display(), e))
+}
+
+/// The published example for `day`.
+pub fn example(day: u8) -> String {
+    read_example(&format!("day{}.txt", day))
+}
+
+/// Solves `parts` of `day` with `params` set, returning just the answers.
+pub fn solve(
+    day: u8,
+    input: &str,
+    parts: &[u8],
+    params: &[(&str, &str)],
+) -> anyhow::Result<Vec<String>> {
+    let mut set = Params::new();
+
+    for (key, value) in params {
+        set.insert(key, value);
+    }
+
+    let solved = days::find(day)
+        .unwrap_or_else(|| panic!("No solutions for day {}", day))
+        .solve_timed(input, parts, &set)?;
+
+    Ok(solved
+        .answers
+        .into_iter()
+        .map(|(answer, _)| answer)
+        .collect())
+}
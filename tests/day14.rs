@@ -0,0 +1,97 @@
+mod common;
+
+use aoc2022::days::{
+    day14::{self, Day14},
+    Solution,
+};
+use common::{example, solve};
+
+/// `check` fails if any method disagrees with the simulation, under each
+/// set of rules. The caves after the example have a shelf over the source, a
+/// shelf with an overhang that sand can't reach under, and rock sitting on
+/// the floor.
+#[test]
+fn methods_agree() {
+    let example = example(14);
+    let caves = [
+        example.as_str(),
+        "497,3 -> 503,3",
+        "490,6 -> 500,6 -> 500,4\n504,8 -> 510,8",
+        "495,11 -> 505,11\n480,9 -> 480,11",
+    ];
+    let rules = ["standard", "diagonal-first", "wind-left", "1,-1", "0,-2,2"];
+
+    for cave in caves {
+        for rules in rules {
+            let solved = solve(14, cave, &[1, 2], &[("method", "check"), ("rules", rules)]);
+
+            assert!(
+                solved.is_ok(),
+                "{} with rules={}: {:#}",
+                cave,
+                rules,
+                solved.unwrap_err()
+            );
+        }
+    }
+}
+
+/// Spelling out the standard moves gives the standard answers. Changing
+/// only their order changes where sand settles, but not how much fits
+/// before the source is blocked.
+#[test]
+fn rules_change_the_example() {
+    let input = example(14);
+
+    for (rules, expected) in [
+        ("standard", ["24", "93"]),
+        ("0,-1,1", ["24", "93"]),
+        ("diagonal-first", ["0", "93"]),
+        ("1,-1", ["0", "52"]),
+    ] {
+        let answers = solve(14, &input, &[1, 2], &[("rules", rules)]).unwrap();
+
+        assert_eq!(answers, expected, "rules={}", rules);
+    }
+}
+
+#[test]
+fn rules_reject_huge_steps() {
+    let input = example(14);
+    let limit = day14::MAX_STEP.to_string();
+    let past_limit = format!("0,-{}", day14::MAX_STEP + 1);
+
+    for (rules, ok) in [
+        (limit.as_str(), true),
+        (past_limit.as_str(), false),
+        ("5000000000000000000", false),
+    ] {
+        let solved = solve(14, &input, &[1, 2], &[("rules", rules)]);
+
+        assert_eq!(solved.is_ok(), ok, "rules={}", rules);
+    }
+}
+
+/// The cave once sand starts falling into the void, as drawn in the puzzle.
+#[test]
+fn render_matches_the_example() {
+    let scan = Day14::parse(&example(14)).unwrap();
+    let mut cave = scan.cave(false);
+    cave.simulate();
+
+    let expected = [
+        "......+...",
+        "..........",
+        "......o...",
+        ".....ooo..",
+        "....#ooo##",
+        "...o#ooo#.",
+        "..###ooo#.",
+        "....oooo#.",
+        ".o.ooooo#.",
+        "#########.",
+    ];
+    let rendered = cave.to_string().replace(' ', "");
+
+    assert_eq!(rendered.lines().collect::<Vec<_>>(), expected);
+}
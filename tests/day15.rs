@@ -0,0 +1,65 @@
+mod common;
+
+use aoc2022::{
+    days::{
+        day15::{self, Day15, Window},
+        Solution,
+    },
+    input,
+};
+use common::{example, read_example, solve};
+
+#[test]
+fn methods_agree() {
+    let input = example(15);
+
+    for method in ["geometry", "scan", "check"] {
+        let answers = solve(15, &input, &[1, 2], &[("method", method)]).unwrap();
+
+        assert_eq!(answers, ["26", "56000011"], "method={}", method);
+    }
+}
+
+#[test]
+#[ignore = "slow in debug builds, run with --release"]
+fn geometry_matches_scan_on_the_real_input() {
+    let input = std::fs::read_to_string(input::default_path(15)).unwrap();
+    let answers = solve(15, &input, &[2], &[("method", "check")]).unwrap();
+
+    assert_eq!(answers, ["12480406634249"]);
+}
+
+/// The example's sensors and beacons in the puzzle's style, with the gap
+/// at 14, 11 the only uncovered spot inside the search area.
+#[test]
+fn render_matches_the_example() {
+    let survey = Day15::parse(&example(15)).unwrap();
+    let expected = read_example("day15_render.txt");
+
+    let window: Window = "-2,0,25,22".parse().unwrap();
+    assert_eq!(day15::render_ascii(&survey.sensors, window, 1), expected);
+
+    let area: Window = "0,0,20,20".parse().unwrap();
+    let gap = survey.find_beacon().unwrap();
+    let image = day15::render_ppm(&survey.sensors, area, 1, Some(gap));
+    let header = b"P6\n21 21\n255\n";
+    let pixel = header.len() + 3 * (gap.y as usize * 21 + gap.x as usize);
+
+    assert!(image.starts_with(header));
+    assert_eq!(image.len(), header.len() + 3 * 21 * 21);
+    assert_eq!(image[pixel..pixel + 3], [0, 255, 80]);
+}
+
+#[test]
+fn tuning_frequency_can_be_negative_but_not_overflow() {
+    let input = example(15);
+
+    for (multiplier, expected) in [("-1", Some("-3")), (&i64::MAX.to_string(), None)] {
+        let solved = solve(15, &input, &[2], &[("multiplier", multiplier)]);
+
+        match expected {
+            Some(expected) => assert_eq!(solved.unwrap(), [expected]),
+            None => assert!(solved.is_err(), "multiplier={}", multiplier),
+        }
+    }
+}
@@ -0,0 +1,202 @@
+mod common;
+
+use aoc2022::{
+    days::{
+        day16::{self, Day16},
+        Solution,
+    },
+    params::Params,
+};
+use common::{example, read_example, solve};
+
+#[test]
+fn methods_agree() {
+    let input = example(16);
+
+    for method in ["exhaustive", "bound", "dp"] {
+        let answers = solve(16, &input, &[1, 2], &[("method", method)]).unwrap();
+
+        assert_eq!(answers, ["1651", "1707"], "method={}", method);
+    }
+}
+
+/// The walkthroughs are the ones in the puzzle, with the minutes it skips
+/// over written out.
+#[test]
+fn narration_matches_the_example() {
+    let input = example(16);
+    let volcano = Day16::parse(&input).unwrap();
+    let network = volcano.compress().unwrap();
+
+    let you = day16::best_alone(&network, volcano.time, volcano.method).best;
+    let narration = day16::narrate(
+        &volcano.network,
+        volcano.time,
+        &[("You", &volcano.network.plan(&you))],
+    );
+    assert_eq!(narration, read_example("day16_part1_walkthrough.txt"));
+
+    let time = volcano.time_with_elephant();
+    let (you, elephant) = day16::best_with_elephant(&network, time, volcano.method).best;
+    let narration = day16::narrate(
+        &volcano.network,
+        time,
+        &[
+            ("You", &volcano.network.plan(&you)),
+            ("The elephant", &volcano.network.plan(&elephant)),
+        ],
+    );
+    assert_eq!(narration, read_example("day16_part2_walkthrough.txt"));
+}
+
+#[test]
+fn dot_draws_the_example() {
+    let input = example(16);
+    let volcano = Day16::parse(&input).unwrap();
+    let network = volcano.compress().unwrap();
+
+    let you = day16::best_alone(&network, volcano.time, volcano.method).best;
+    let dot = volcano
+        .network
+        .to_dot(&[("You", &volcano.network.plan(&you))]);
+    let lines: Vec<&str> = dot.lines().collect();
+
+    assert_eq!(lines.first(), Some(&"graph valves {"));
+    assert_eq!(lines.last(), Some(&"}"));
+
+    // The valves with no flow are greyed out.
+    let grey: Vec<&str> = lines
+        .iter()
+        .filter(|l| l.contains("fillcolor=lightgrey"))
+        .map(|l| l.trim().split('"').nth(1).unwrap())
+        .collect();
+    assert_eq!(grey, ["AA", "FF", "GG", "II"]);
+
+    // Each of the ten tunnels is drawn once, however many ends list it.
+    let tunnels: Vec<&str> = lines
+        .iter()
+        .filter(|l| l.contains(" -- ") && !l.contains('['))
+        .map(|l| l.trim())
+        .collect();
+    assert_eq!(tunnels.len(), 10);
+    assert!(tunnels.contains(&"\"AA\" -- \"DD\";"));
+    assert!(!tunnels.contains(&"\"DD\" -- \"AA\";"));
+
+    // The route's moves are numbered in order, each starting where the last
+    // one ended.
+    let route: Vec<(&str, &str, &str)> = lines
+        .iter()
+        .filter(|l| l.contains("tooltip=\"You\""))
+        .map(|l| {
+            let parts: Vec<&str> = l.split('"').collect();
+            (parts[1], parts[3], parts[5])
+        })
+        .collect();
+    assert_eq!(route.len(), 18);
+    assert_eq!(route[0], ("AA", "DD", "1"));
+    assert_eq!(route[17], ("DD", "CC", "18"));
+
+    for (i, pair) in route.windows(2).enumerate() {
+        assert_eq!(pair[0].1, pair[1].0);
+        assert_eq!(pair[1].2, (i + 2).to_string());
+    }
+}
+
+#[test]
+fn budgets_match_the_answers() {
+    let input = example(16);
+    let network = Day16::parse(&input).unwrap().compress().unwrap();
+
+    let budgets = day16::best_pressure_per_budget(&network, 30).best;
+    let (you, elephant) = day16::best_disjoint_pair(&budgets[26]);
+
+    assert_eq!(budgets[30].values().max(), Some(&1651));
+    assert_eq!(budgets[26][&you] + budgets[26][&elephant], 1707);
+}
+
+#[test]
+fn rejects_times_past_the_limit() {
+    let input = example(16);
+    let mut volcano = Day16::parse(&input).unwrap();
+
+    for (time, ok) in [
+        (day16::MAX_TIME, true),
+        (day16::MAX_TIME + 1, false),
+        (u64::MAX, false),
+    ] {
+        let mut params = Params::new();
+        params.insert("time", &time.to_string());
+
+        assert_eq!(
+            Day16::configure(&mut volcano, &params).is_ok(),
+            ok,
+            "time={}",
+            time
+        );
+
+        let mut params = Params::new();
+        params.insert("open_time", &time.to_string());
+
+        assert_eq!(
+            Day16::configure(&mut volcano, &params).is_ok(),
+            ok,
+            "open_time={}",
+            time
+        );
+    }
+}
+
+#[test]
+fn names_are_interned() {
+    // A ring of 1000 valves, more than two letters can name, with a flow rate
+    // at the valves 3 and 997 steps round from the start.
+    let ring: String = (0..1000)
+        .map(|i| {
+            let rate = if i == 3 || i == 997 { 10 } else { 0 };
+            format!(
+                "Valve {} has flow rate={}; tunnels lead to valves {}, {}\n",
+                ring_name(i),
+                rate,
+                ring_name((i + 999) % 1000),
+                ring_name((i + 1) % 1000)
+            )
+        })
+        .collect();
+
+    let answers = solve(16, &ring, &[1], &[]).unwrap();
+    assert_eq!(answers, ["450"]);
+
+    // Names can be anything without whitespace, commas or semicolons.
+    let punctuated = "Valve AA has flow rate=0; tunnels lead to valves pump-3, v_1\n\
+                      Valve pump-3 has flow rate=5; tunnel leads to valve AA\n\
+                      Valve v_1 has flow rate=7; tunnel leads to valve AA";
+    let answers = solve(16, punctuated, &[1], &[]).unwrap();
+    assert_eq!(answers, ["321"]);
+
+    // BB is taken again before AA is, so it's the one reported.
+    let duplicate = "Valve BB has flow rate=1; tunnels lead to valves AA\n\
+                     Valve AA has flow rate=0; tunnels lead to valves BB\n\
+                     Valve BB has flow rate=2; tunnels lead to valves AA\n\
+                     Valve AA has flow rate=3; tunnels lead to valves BB";
+    let error = Day16::parse(duplicate).unwrap_err();
+    assert_eq!(
+        (error.line, error.column, error.text.as_str()),
+        (3, 7, "BB")
+    );
+
+    let dangling = "Valve AA has flow rate=0; tunnels lead to valves BB, CC\n\
+                    Valve BB has flow rate=1; tunnels lead to valves AA";
+    let error = Day16::parse(dangling).unwrap_err();
+    assert_eq!(
+        (error.line, error.column, error.text.as_str()),
+        (1, 54, "CC")
+    );
+}
+
+fn ring_name(i: usize) -> String {
+    if i == 0 {
+        day16::START.to_string()
+    } else {
+        format!("V{}", i)
+    }
+}
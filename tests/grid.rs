@@ -0,0 +1,104 @@
+use aoc2022::grid::{Grid, Point};
+
+fn digits(map: &str) -> Grid<u32> {
+    Grid::parse(map, "a digit", |ch| ch.to_digit(10)).unwrap()
+}
+
+/// A 4x3 grid where each cell holds `10 * row + column`.
+fn numbered() -> Grid<u32> {
+    let mut grid = Grid::new(4, 3, 0);
+
+    for p in grid.points().collect::<Vec<_>>() {
+        grid[p] = 10 * p.y as u32 + p.x as u32;
+    }
+
+    grid
+}
+
+#[test]
+fn parse_reads_rows_of_cells() {
+    let grid = digits("123\n456\n");
+
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[Point::new(0, 0)], 1);
+    assert_eq!(grid[Point::new(2, 1)], 6);
+    assert_eq!(grid.position(|&d| d == 5), Some(Point::new(1, 1)));
+}
+
+#[test]
+fn parse_reports_bad_cells_and_ragged_rows() {
+    let err = Grid::parse("123\n4x6", "a digit", |ch| ch.to_digit(10)).unwrap_err();
+    assert_eq!((err.line, err.column), (2, 2));
+    assert_eq!(err.expected, "a digit");
+    assert_eq!(err.text, "x");
+
+    let err = Grid::parse("123\n45", "a digit", |ch| ch.to_digit(10)).unwrap_err();
+    assert_eq!((err.line, err.column), (2, 1));
+    assert_eq!(err.expected, "a row of 3 cells");
+
+    let err = Grid::parse("", "a digit", |ch| ch.to_digit(10)).unwrap_err();
+    assert_eq!(err.expected, "at least one row of cells");
+}
+
+#[test]
+fn rows_and_columns() {
+    let grid = numbered();
+
+    assert_eq!(grid.row(1).copied().collect::<Vec<_>>(), [10, 11, 12, 13]);
+    assert_eq!(grid.column(3).copied().collect::<Vec<_>>(), [3, 13, 23]);
+    assert_eq!(grid.rows().count(), 3);
+    assert_eq!(grid.rows().last().unwrap(), [20, 21, 22, 23]);
+}
+
+#[test]
+#[should_panic(expected = "row 3 is outside the 4x3 grid")]
+fn row_past_the_bottom_panics() {
+    numbered().row(3).count();
+}
+
+#[test]
+#[should_panic(expected = "column 4 is outside the 4x3 grid")]
+fn column_past_the_edge_panics() {
+    numbered().column(4).count();
+}
+
+#[test]
+fn neighbors_stay_on_the_grid() {
+    let grid = numbered();
+
+    assert_eq!(grid.neighbors4(Point::new(0, 0)).count(), 2);
+    assert_eq!(grid.neighbors8(Point::new(0, 0)).count(), 3);
+    assert_eq!(grid.neighbors8(Point::new(3, 1)).count(), 5);
+    assert_eq!(grid.neighbors8(Point::new(1, 1)).count(), 8);
+
+    let mut around: Vec<Point> = grid.neighbors8(Point::new(3, 2)).collect();
+    around.sort();
+
+    assert_eq!(
+        around,
+        [Point::new(2, 1), Point::new(2, 2), Point::new(3, 1)]
+    );
+}
+
+#[test]
+fn rays_stop_at_the_edge() {
+    let grid = numbered();
+
+    let right: Vec<Point> = grid.ray(Point::new(1, 1), Point::RIGHT).collect();
+    assert_eq!(right, [Point::new(2, 1), Point::new(3, 1)]);
+
+    let up_left: Vec<Point> = grid.ray(Point::new(2, 2), Point::new(-1, -1)).collect();
+    assert_eq!(up_left, [Point::new(1, 1), Point::new(0, 0)]);
+
+    assert_eq!(grid.ray(Point::new(0, 1), Point::LEFT).count(), 0);
+}
+
+#[test]
+fn get_is_none_off_the_grid() {
+    let grid = numbered();
+
+    assert_eq!(grid.get(Point::new(3, 2)), Some(&23));
+    assert_eq!(grid.get(Point::new(4, 0)), None);
+    assert_eq!(grid.get(Point::new(0, -1)), None);
+    assert!(!grid.contains(Point::new(-1, 0)));
+}
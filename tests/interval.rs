@@ -0,0 +1,162 @@
+//! Property tests for `IntervalSet`, comparing every operation against a
+//! bitset over a small range of values.
+
+use aoc2022::interval::IntervalSet;
+use std::ops::RangeInclusive;
+
+const MIN: isize = -64;
+const MAX: isize = 63;
+const CASES: usize = 2000;
+
+/// A xorshift generator, so failures can be reproduced from the seed.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn value(&mut self) -> isize {
+        MIN + (self.next() % (MAX - MIN + 1) as u64) as isize
+    }
+
+    /// A range that is empty about one time in four.
+    fn range(&mut self) -> RangeInclusive<isize> {
+        let start = self.value();
+        let length = (self.next() % 24) as isize - 6;
+
+        start..=(start + length).min(MAX)
+    }
+
+    fn ranges(&mut self) -> Vec<RangeInclusive<isize>> {
+        let count = self.next() % 7;
+
+        (0..count).map(|_| self.range()).collect()
+    }
+}
+
+/// Bit `v - MIN` is set for each value `v` in the set.
+type Bits = u128;
+
+fn range_bits(range: &RangeInclusive<isize>) -> Bits {
+    range
+        .clone()
+        .fold(0, |bits, v| bits | 1 << (v - MIN) as u32)
+}
+
+fn model(ranges: &[RangeInclusive<isize>]) -> Bits {
+    ranges.iter().fold(0, |bits, r| bits | range_bits(r))
+}
+
+fn bits(set: &IntervalSet) -> Bits {
+    model(&set.ranges().collect::<Vec<_>>())
+}
+
+fn build(ranges: &[RangeInclusive<isize>]) -> IntervalSet {
+    let mut set = IntervalSet::new();
+
+    for range in ranges {
+        set.insert(range.clone());
+    }
+
+    set
+}
+
+fn assert_canonical(set: &IntervalSet) {
+    let ranges: Vec<_> = set.ranges().collect();
+
+    assert!(ranges.iter().all(|r| r.start() <= r.end()), "{:?}", ranges);
+    assert!(
+        ranges.windows(2).all(|p| p[0].end() + 1 < *p[1].start()),
+        "{:?}",
+        ranges
+    );
+}
+
+fn check_case(rng: &mut Rng) {
+    let a_ranges = rng.ranges();
+    let b_ranges = rng.ranges();
+    let (a, b) = (build(&a_ranges), build(&b_ranges));
+    let (a_bits, b_bits) = (model(&a_ranges), model(&b_ranges));
+    let context = format!("a = {:?}, b = {:?}", a_ranges, b_ranges);
+
+    for set in [&a, &b] {
+        assert_canonical(set);
+    }
+
+    assert_eq!(bits(&a), a_bits, "{}", context);
+    assert_eq!(a, a_ranges.iter().cloned().collect(), "{}", context);
+    assert_eq!(a.len(), a_bits.count_ones() as usize, "{}", context);
+    assert_eq!(a.is_empty(), a_bits == 0, "{}", context);
+
+    for v in MIN..=MAX {
+        assert_eq!(
+            a.contains(v),
+            a_bits & range_bits(&(v..=v)) != 0,
+            "{} {}",
+            context,
+            v
+        );
+    }
+
+    let query = rng.range();
+    let query_bits = range_bits(&query);
+    assert_eq!(
+        a.contains_range(query.clone()),
+        a_bits & query_bits == query_bits,
+        "{} {:?}",
+        context,
+        query
+    );
+    assert_eq!(
+        a.overlaps(query.clone()),
+        a_bits & query_bits != 0,
+        "{} {:?}",
+        context,
+        query
+    );
+
+    let union = a.union(&b);
+    let intersection = a.intersection(&b);
+    let difference = a.difference(&b);
+
+    for set in [&union, &intersection, &difference] {
+        assert_canonical(set);
+    }
+
+    assert_eq!(bits(&union), a_bits | b_bits, "{}", context);
+    assert_eq!(bits(&intersection), a_bits & b_bits, "{}", context);
+    assert_eq!(bits(&difference), a_bits & !b_bits, "{}", context);
+    assert_eq!(a.is_subset(&b), a_bits & !b_bits == 0, "{}", context);
+
+    let bounds = rng.range();
+    let gaps: Vec<_> = a.gaps(bounds.clone()).collect();
+    assert_canonical(&gaps.iter().cloned().collect());
+    assert_eq!(
+        model(&gaps),
+        range_bits(&bounds) & !a_bits,
+        "{} {:?}",
+        context,
+        bounds
+    );
+}
+
+#[test]
+fn matches_a_bitset() {
+    let mut rng = Rng(0x2022_1215);
+
+    for _ in 0..CASES {
+        check_case(&mut rng);
+    }
+}
+
+#[test]
+fn merges_touching_ranges() {
+    let set: IntervalSet = [1..=3, 7..=9, 4..=6].into_iter().collect();
+
+    assert_eq!(set.ranges().collect::<Vec<_>>(), vec![1..=9]);
+    assert_eq!(set.gaps(0..=12).collect::<Vec<_>>(), vec![0..=0, 10..=12]);
+}
@@ -0,0 +1,115 @@
+//! Checks every solution against known answers.
+//!
+//! The published examples live in `tests/examples/` next to their expected
+//! answers. Answers for the real puzzle inputs are optional and are read from
+//! `src/inputs/answers.txt` when it exists, since not everyone checks in their
+//! inputs. Tests of each day's internals live in that day's own file.
+
+mod common;
+
+use aoc2022::{days, input};
+use common::examples_dir;
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+type Answers = BTreeMap<u8, Vec<(u8, String)>>;
+
+/// Reads an answers file made of `<day> <part> <answer>` lines, where a
+/// newline inside an answer is written as `\n`. Blank lines and lines starting
+/// with `#` are skipped.
+fn read_answers(path: &Path) -> Answers {
+    let contents = std::fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("Could not read {}: {}", path.display(), e));
+
+    let mut answers = Answers::new();
+
+    for line in contents.lines() {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let mut fields = line.splitn(3, ' ');
+        let mut next_number = || -> u8 {
+            fields
+                .next()
+                .and_then(|f| f.parse().ok())
+                .unwrap_or_else(|| panic!("Malformed line in {}: {}", path.display(), line))
+        };
+
+        let day = next_number();
+        let part = next_number();
+        let answer = fields.next().unwrap_or_default().replace("\\n", "\n");
+
+        answers.entry(day).or_default().push((part, answer));
+    }
+
+    answers
+}
+
+/// Solves each day's input and returns a description of every wrong answer.
+fn check(answers: &Answers, input_path: impl Fn(u8) -> PathBuf) -> Vec<String> {
+    let mut failures = vec![];
+
+    for (&day, expected) in answers {
+        let solution = days::find(day).unwrap_or_else(|| panic!("No solutions for day {}", day));
+        let path = input_path(day);
+        let input = std::fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("Could not read {}: {}", path.display(), e));
+
+        let parts: Vec<u8> = expected.iter().map(|(p, _)| *p).collect();
+
+        match solution.solve(&input, &parts) {
+            Ok(actual) => {
+                for ((part, expected), actual) in expected.iter().zip(actual) {
+                    if *expected != actual {
+                        failures.push(format!(
+                            "day {} part {} ({}): expected {:?}, got {:?}",
+                            day,
+                            part,
+                            path.display(),
+                            expected,
+                            actual
+                        ));
+                    }
+                }
+            }
+            Err(e) => failures.push(format!("day {} ({}): {:#}", day, path.display(), e)),
+        }
+    }
+
+    failures
+}
+
+fn real_answers() -> Option<Answers> {
+    let path = input::inputs_dir().join("answers.txt");
+
+    if path.exists() {
+        Some(read_answers(&path))
+    } else {
+        eprintln!("{} not found, skipping real inputs", path.display());
+        None
+    }
+}
+
+#[test]
+fn examples() {
+    let answers = read_answers(&examples_dir().join("answers.txt"));
+    let failures = check(&answers, |day| {
+        examples_dir().join(format!("day{}.txt", day))
+    });
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn real_inputs() {
+    let Some(answers) = real_answers() else {
+        return;
+    };
+
+    let failures = check(&answers, input::default_path);
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
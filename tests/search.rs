@@ -0,0 +1,105 @@
+use aoc2022::{
+    grid::{Grid, Point},
+    search::{self, Path},
+};
+
+/// A small weighted graph: the direct edge 0 -> 3 is dearer than going round.
+fn weighted(node: &u8) -> Vec<(u8, usize)> {
+    match node {
+        0 => vec![(1, 1), (3, 10)],
+        1 => vec![(2, 2)],
+        2 => vec![(3, 3)],
+        _ => vec![],
+    }
+}
+
+fn maze() -> Grid<bool> {
+    let map = "\
+.#....
+.#.##.
+...#..
+##.#.#
+......";
+
+    Grid::parse(map, "a wall or floor", |ch| match ch {
+        '.' => Some(true),
+        '#' => Some(false),
+        _ => None,
+    })
+    .unwrap()
+}
+
+#[test]
+fn bfs_counts_steps_and_ignores_costs() {
+    let path = search::bfs(&weighted, 0, |&n| n == 3).unwrap();
+
+    assert_eq!(
+        path,
+        Path {
+            cost: 1,
+            nodes: vec![0, 3]
+        }
+    );
+}
+
+#[test]
+fn dijkstra_follows_the_cheapest_edges() {
+    let path = search::dijkstra(&weighted, 0, |&n| n == 3).unwrap();
+
+    assert_eq!(
+        path,
+        Path {
+            cost: 6,
+            nodes: vec![0, 1, 2, 3]
+        }
+    );
+}
+
+#[test]
+fn unreachable_goals_are_none() {
+    assert_eq!(search::bfs(&weighted, 3, |&n| n == 0), None);
+    assert_eq!(search::dijkstra(&weighted, 2, |&n| n == 1), None);
+    assert_eq!(search::bfs_all(&weighted, 1).cost(&0), None);
+}
+
+#[test]
+fn searches_agree_on_a_grid() {
+    let grid = maze();
+    let open = |p: &Point| {
+        grid.neighbors4(*p)
+            .filter(|&n| grid[n])
+            .map(|n| (n, 1))
+            .collect::<Vec<_>>()
+    };
+
+    let start = Point::new(0, 0);
+    let end = Point::new(5, 0);
+
+    let bfs = search::bfs(&open, start, |&p| p == end).unwrap();
+    let dijkstra = search::dijkstra(&open, start, |&p| p == end).unwrap();
+    let astar = search::astar(&open, start, |&p| p == end, |p| p.manhattan_distance(end)).unwrap();
+
+    assert_eq!(bfs.cost, 9);
+    assert_eq!(dijkstra.cost, bfs.cost);
+    assert_eq!(astar.cost, bfs.cost);
+
+    for path in [bfs, dijkstra, astar] {
+        assert_eq!(path.nodes.len(), path.cost + 1);
+        assert_eq!(path.nodes.first(), Some(&start));
+        assert_eq!(path.nodes.last(), Some(&end));
+        assert!(path
+            .nodes
+            .windows(2)
+            .all(|pair| grid[pair[1]] && pair[0].manhattan_distance(pair[1]) == 1));
+    }
+}
+
+#[test]
+fn shortest_paths_to_every_node() {
+    let paths = search::dijkstra_all(&weighted, 0);
+
+    assert_eq!(paths.reached().count(), 4);
+    assert_eq!(paths.cost(&2), Some(3));
+    assert_eq!(paths.parent(&3), Some(&2));
+    assert_eq!(paths.path(&3).unwrap().nodes, vec![0, 1, 2, 3]);
+}